@@ -1,7 +1,17 @@
 // Services
 mod services {
+    pub mod cli;
+    pub mod content;
+    pub mod content_validation;
+    pub mod displayer;
     pub mod displayer_bevy;
+    pub mod engine;
+    pub mod headless;
     pub mod json_loader;
+    pub mod replay;
+    pub mod save_migration;
+    pub mod session;
+    pub mod tui;
     pub mod ui;
 }
 
@@ -9,11 +19,17 @@ mod services {
 mod models {
     pub mod aptitude;
     pub mod arena;
+    pub mod attack;
     pub mod badge;
+    pub mod enigma;
     pub mod ingredient;
+    pub mod inventory;
+    pub mod level;
     pub mod recipe;
+    pub mod save_game;
     pub mod caracter {
         pub mod bouncer;
+        #[allow(clippy::module_inception)]
         pub mod caracter;
         pub mod client;
         pub mod master;
@@ -23,25 +39,100 @@ mod models {
     }
 }
 
-use services::{displayer_bevy, json_loader::JsonLoader};
+use services::cli::{CliArgs, Frontend, USAGE};
+use services::json_loader::{JsonLoader, LoadError};
+use services::session::SaveDirectory;
+use services::replay::{RecordedInput, Replay};
+use services::tui::app::{TuiApp, TuiInput};
+use services::ui::replay::BevyInput;
+use services::{content::GameContent, content_validation, displayer, displayer_bevy, headless};
 use std::io::{self};
 
 // Point d'entrée principal du jeu
 fn main() -> io::Result<()> {
-    // Chemin pour le fichier des aptitudes
-    let file_path = "assets/caracters/aptitudes.json";
-
-    // On charge les aptitudes depuis le fichier JSON
-    let aptitudes = match JsonLoader::load_json_aptitudes(file_path) {
-        Ok(apt) => apt,
-        Err(_e) => {
-            // Si le fichier n'existe pas
-            vec![]
+    // Options de la ligne de commande : dossiers, interface, slot et arène de départ
+    let args = match CliArgs::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            std::process::exit(2);
         }
     };
+    if args.help {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+
+    // Mode validation pour l'équipe contenu : on vérifie les données sans lancer le jeu
+    if args.validate_content {
+        let report = content_validation::validate_asset_dir(&args.asset_dir);
+        println!("{}", report);
+        std::process::exit(if report.is_valid() { 0 } else { 1 });
+    }
+
+    // On charge tout le contenu du jeu une seule fois
+    // Les erreurs sont affichées à l'écran au démarrage plutôt que d'être ignorées
+    let (content, load_errors) = match GameContent::load(&args.asset_dir) {
+        Ok(content) => (content, vec![]),
+        Err(errors) => {
+            for e in &errors {
+                println!("Erreur de chargement: {}", e);
+            }
+            (GameContent::default(), errors)
+        }
+    };
+
+    match args.frontend {
+        // On lance l'interface graphique, avec la session Bevy à rejouer s'il y en a une
+        Frontend::Bevy => {
+            let replay = load_replay::<BevyInput>(&args);
+            let displayer_bevy = displayer_bevy::DisplayerBevy::new();
+            displayer_bevy.run(content, load_errors, &args, replay)
+        }
+        // Interface terminal pour jouer sans fenêtre, par exemple en SSH
+        Frontend::Tui => {
+            let app = tui_app(content, load_errors, &args);
+            let mut displayer = displayer::Displayer::new()?;
+            displayer.run(app)
+        }
+        // Partie pilotée par l'entrée standard, pour les scripts et les tests
+        // Une session enregistrée dans le terminal est rejouée sans affichage
+        Frontend::Headless => match load_replay::<TuiInput>(&args) {
+            Some(replay) => {
+                headless::replay(content, load_errors, replay, io::stdout()).map(|_| ())
+            }
+            None => {
+                let mut app = tui_app(content, load_errors, &args);
+                headless::run(&mut app, io::stdin().lock(), io::stdout())
+            }
+        },
+    }
+}
+
+// Session enregistrée à rejouer à la place du joueur, avec l'interface qui l'a enregistrée
+fn load_replay<I: RecordedInput>(args: &CliArgs) -> Option<Replay<I>> {
+    let path = args.replay.as_ref()?;
+    match JsonLoader::load_replay(path) {
+        Ok(replay) => Some(replay),
+        Err(e) => {
+            eprintln!("Impossible de lire le rejeu: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
 
-    // On lance l'interface graphique
-    let displayer_bevy = displayer_bevy::DisplayerBevy::new();
-    let _ = displayer_bevy.run(&aptitudes);
-    Ok(())
+// Prépare l'interface terminal, directement en jeu si un slot ou une arène est demandé
+// Les entrées du joueur sont enregistrées si un fichier de rejeu est donné
+fn tui_app(content: GameContent, load_errors: Vec<LoadError>, args: &CliArgs) -> TuiApp {
+    let mut app = TuiApp::new(
+        content,
+        load_errors,
+        SaveDirectory(args.save_dir.clone()),
+        args.seed,
+    );
+    if let Some(path) = &args.record {
+        app.record_replay(path.clone(), args.slot, args.arena.clone());
+    }
+    app.start_with(args.slot, args.arena.as_deref());
+    app
 }
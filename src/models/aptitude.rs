@@ -2,12 +2,27 @@ use serde_derive::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 
-// Aptitude avec un nom, description, coût et puissance
+// Aptitude avec un nom, description, coût, puissance et effet en combat
 pub struct Aptitude {
     pub name: String,
     pub description: String,
     pub pp: i32,
     pub power: f32,
+    #[serde(default)]
+    pub effect: Option<AptitudeEffect>,
+}
+
+/// Effet d'une aptitude utilisée en combat, déclaré dans `aptitudes.json`.
+/// La `power` de l'aptitude sert de paramètre à l'effet.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum AptitudeEffect {
+    /// Les prochains dégâts infligés au boss sont multipliés par `power`
+    DamageBoost,
+    /// Révèle `power` ingrédients corrects de la recette (au moins un)
+    RevealIngredient,
+    /// Bloque entièrement la prochaine attaque du boss
+    Shield,
 }
 
 impl Aptitude {}
@@ -4,19 +4,45 @@ use serde_derive::{Deserialize, Serialize};
 
 /// Représente une arène dans le jeu
 /// avec un nom et un thème.
+/// Le maître et le videur de l'arène sont désignés par leur `id`, l'ordre des fichiers n'a
+/// donc pas d'importance. Une arène est verrouillée tant que les arènes listées dans
+/// `unlock_requirements` n'ont pas été gagnées ou que le joueur ne possède pas les badges
+/// de `required_badges`, et une arène `hidden` n'apparaît qu'une fois déverrouillée.
+/// L'arène du `championship` demande tous les badges des autres arènes.
 ///
 /// # Exemple
 /// ```
 /// let arena = Arena {
+///     id: "marseille".to_string(),
 ///     name: "Marseille".to_string(),
 ///     theme: "Pastis".to_string(),
+///     master_id: "pastis_man".to_string(),
+///     bouncer_id: "ragnar".to_string(),
+///     difficulty: 1,
+///     unlock_requirements: vec![],
+///     required_badges: vec![],
+///     hidden: false,
+///     championship: false,
 /// };
 /// assert_eq!(arena.name, "Marseille");
 /// assert_eq!(arena.theme, "Pastis");
 /// ```
 pub struct Arena {
+    pub id: String,
     pub name: String,
-    pub theme: String
+    pub theme: String,
+    pub master_id: String,
+    pub bouncer_id: String,
+    #[serde(default)]
+    pub difficulty: u32,
+    #[serde(default)]
+    pub unlock_requirements: Vec<String>,
+    #[serde(default)]
+    pub required_badges: Vec<String>,
+    #[serde(default)]
+    pub hidden: bool,
+    #[serde(default)]
+    pub championship: bool,
 }
 
 impl Arena {
@@ -0,0 +1,64 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Représente une attaque d'un maître d'arène.
+/// Une attaque a des dégâts, une précision (probabilité de toucher entre 0 et 1)
+/// et peut appliquer un effet de statut au joueur.
+///
+/// # Exemple
+/// ```
+/// let attack = Attack {
+///     name: "Jet d'Anis".to_string(),
+///     damage: 10,
+///     accuracy: 0.8,
+///     status_effect: Some(StatusEffect::Poison { damage: 3, turns: 2 }),
+/// };
+/// assert_eq!(attack.damage, 10);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Attack {
+    pub name: String,
+    pub damage: i32,
+    pub accuracy: f32,
+    #[serde(default)]
+    pub status_effect: Option<StatusEffect>,
+}
+
+/// Effets de statut qu'une attaque peut infliger au joueur pendant quelques tours.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum StatusEffect {
+    /// Le joueur perd `damage` HP au début de chaque tour du boss
+    Poison { damage: i32, turns: u32 },
+    /// Les dégâts infligés par le joueur au boss sont multipliés par `factor`
+    Weakness { factor: f32, turns: u32 },
+}
+
+impl StatusEffect {
+    /// Nombre de tours restants pour cet effet
+    pub fn turns(&self) -> u32 {
+        match self {
+            StatusEffect::Poison { turns, .. } | StatusEffect::Weakness { turns, .. } => *turns,
+        }
+    }
+
+    /// Décrémente d'un tour la durée de l'effet
+    pub fn tick(&mut self) {
+        match self {
+            StatusEffect::Poison { turns, .. } | StatusEffect::Weakness { turns, .. } => {
+                *turns = turns.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Nom affichable de l'effet
+    pub fn label(&self) -> String {
+        match self {
+            StatusEffect::Poison { damage, turns } => {
+                format!("Empoisonne (-{} HP, {} tour(s))", damage, turns)
+            }
+            StatusEffect::Weakness { factor, turns } => {
+                format!("Affaibli (degats x{}, {} tour(s))", factor, turns)
+            }
+        }
+    }
+}
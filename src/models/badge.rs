@@ -2,10 +2,54 @@ use serde_derive::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 
-// Un badge avec nom et liste de caractéristiques
+// Un badge avec nom, liste de caractéristiques et effets passifs.
+// Les effets s'appliquent en jeu tant que le joueur possède le badge.
 pub struct Badge {
     pub name: String,
     pub features: Vec<String>,
+    #[serde(default)]
+    pub effects: Vec<BadgeEffect>,
 }
 
 impl Badge {}
+
+/// Effet passif d'un badge, déclaré avec le badge dans les fichiers de données.
+///
+/// # Exemple
+/// ```
+/// let effect: BadgeEffect = serde_json::from_str(r#"{"type": "TraderDiscount", "percent": 10}"#).unwrap();
+/// assert_eq!(effect, BadgeEffect::TraderDiscount { percent: 10 });
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum BadgeEffect {
+    /// Les dégâts infligés au boss augmentent de `percent` % dans les arènes du thème `theme`,
+    /// ou dans toutes les arènes si aucun thème n'est donné
+    DamageBonus {
+        #[serde(default)]
+        theme: Option<String>,
+        percent: u32,
+    },
+    /// Un ingrédient correct de la recette est révélé au début de chaque combat
+    IngredientHint,
+    /// Les prix des marchands baissent de `percent` %
+    TraderDiscount { percent: u32 },
+}
+
+impl BadgeEffect {
+    /// Description affichable de l'effet
+    pub fn label(&self) -> String {
+        match self {
+            BadgeEffect::DamageBonus { theme: Some(theme), percent } => {
+                format!("+{}% de degats dans les arenes {}", percent, theme)
+            }
+            BadgeEffect::DamageBonus { theme: None, percent } => {
+                format!("+{}% de degats", percent)
+            }
+            BadgeEffect::IngredientHint => "Un ingredient revele a chaque combat".to_string(),
+            BadgeEffect::TraderDiscount { percent } => {
+                format!("-{}% chez les marchands", percent)
+            }
+        }
+    }
+}
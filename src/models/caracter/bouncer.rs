@@ -1,25 +1,49 @@
 use super::pnj::Pnj;
+use crate::models::enigma::Enigma;
 use serde_derive::{Deserialize, Serialize};
 
+/// Façon dont le joueur répond aux énigmes d'un videur
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
+pub enum AnswerMode {
+    /// Le joueur choisit parmi la réponse et les distracteurs
+    #[default]
+    Choices,
+    /// Le joueur tape sa réponse, comparée aux réponses acceptées
+    FreeText,
+}
+
 /// Représente un Bouncer, un PNJ qui garde l'entrée d'un lieu et pose des énigmes.
 /// Il hérite des caractéristiques d'un PNJ et possède des énigmes à résoudre.
+/// Le mode de réponse (choix ou saisie libre) est choisi par videur dans les données.
 ///
 /// # Exemple
 /// ```
 /// let bouncer = Bouncer {
-///     pnj: Pnj::new("Bouncer", "A tough-looking bouncer"),
-///     enigmas: vec![
-///         "What has keys but can't open locks?".to_string(),
-///         "I speak without a mouth and hear without ears. What am I?".to_string(),
-///     ],
+///     id: "bouncer".to_string(),
+///     pnj: Pnj {
+///         caracter: Caracter::new("Bouncer", "Costaud", 100, 50, 0),
+///         job: "Videur".to_string(),
+///         dialogs: vec![],
+///     },
+///     enigmas: vec![Enigma {
+///         question: "Qu'est-ce qui a des clés mais n'ouvre aucune porte ?".to_string(),
+///         answer: "Un piano".to_string(),
+///         distractors: vec!["Un gardien".to_string()],
+///         hint: None,
+///         accepted_answers: vec!["Un clavecin".to_string()],
+///     }],
+///     answer_mode: AnswerMode::FreeText,
 /// };
-/// assert_eq!(bouncer.pnj.name, "Bouncer");
-/// assert_eq!(bouncer.enigmas.len(), 2);
+/// assert_eq!(bouncer.pnj.caracter.name, "Bouncer");
+/// assert_eq!(bouncer.enigmas.len(), 1);
 /// ```
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Bouncer {
+    pub id: String,
     pub pnj: Pnj,
-    pub enigmas: Vec<String>,
+    pub enigmas: Vec<Enigma>,
+    #[serde(default)]
+    pub answer_mode: AnswerMode,
 }
 
 impl Bouncer {
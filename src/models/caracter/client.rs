@@ -1,11 +1,40 @@
 use super::pnj::Pnj;
+use crate::models::recipe::Recipe;
 use serde_derive::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Représente un client du bar, un PNJ qui commande une boisson entre deux arènes.
+/// Le joueur prépare sa commande avec ses ingrédients et les instructions de la recette,
+/// puis reçoit un pourboire calculé à partir de `tips` selon sa rapidité et sa précision.
+///
+/// # Exemple
+/// ```
+/// let client = Client {
+///     pnj: Pnj {
+///         caracter: Caracter::new("Hugo", "Habitué du comptoir", 100, 0, 20),
+///         job: "Client".to_string(),
+///         dialogs: vec!["Un perroquet, s'il vous plait !".to_string()],
+///     },
+///     tips: 5,
+///     advices: vec!["Le pastis se sert toujours frais.".to_string()],
+///     drink: "Perroquet".to_string(),
+///     order: Recipe {
+///         ingredients: vec![RecipeItem {
+///             ingredient: "Pastis".to_string(),
+///             quantity: 2,
+///             unit: "cl".to_string(),
+///         }],
+///         instructions: vec!["Verser le Pastis".to_string(), "Ajouter le sirop de menthe".to_string()],
+///     },
+/// };
+/// assert_eq!(client.tips, 5);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Client {
-    pub pnj: Pnj,  
+    pub pnj: Pnj,
     pub tips: i32,
     pub advices: Vec<String>,
+    pub drink: String,
+    pub order: Recipe,
 }
 
 impl Client {
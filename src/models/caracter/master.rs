@@ -1,3 +1,4 @@
+use crate::models::attack::Attack;
 use crate::models::badge::Badge;
 use crate::models::caracter::pnj::Pnj;
 use crate::models::recipe::Recipe;
@@ -7,27 +8,45 @@ use serde_derive::{Deserialize, Serialize};
 /// Représente un maître PNJ, qui est un personnage non-joueur avec des compétences de combat
 /// et une recette à enseigner.
 /// Il hérite des caractéristiques d'un PNJ et possède un badge, des attaques et une recette.
+/// Ses HP de boss sont ceux de son `Caracter`.
 ///
 /// # Exemple
 /// ```
 /// let master = Master {
-///     pnj: Pnj::new("Master", "A wise and powerful master"),
+///     id: "master".to_string(),
+///     pnj: Pnj {
+///         caracter: Caracter::new("Master", "Classique", 120, 50, 0),
+///         job: "Maitre d'arene".to_string(),
+///         dialogs: vec!["En garde !".to_string()],
+///     },
 ///     badge: Badge {
 ///         name: "Master Badge".to_string(),
 ///         features: vec!["Combat Expert".to_string(), "Recipe Master".to_string()],
+///         effects: vec![BadgeEffect::IngredientHint],
 ///     },
-///     attacks: vec!["Punch".to_string(), "Kick".to_string()],
+///     attacks: vec![Attack {
+///         name: "Punch".to_string(),
+///         damage: 10,
+///         accuracy: 0.9,
+///         status_effect: None,
+///     }],
 ///     recipe: Recipe {
-///         name: "Master's Special Dish".to_string(),
-///         ingredients: vec!["Ingredient1".to_string(), "Ingredient2".to_string()],
-///         instructions: "Mix ingredients and cook for 30 minutes.".to_string(),
+///         ingredients: vec![RecipeItem {
+///             ingredient: "Pastis".to_string(),
+///             quantity: 2,
+///             unit: "cl".to_string(),
+///         }],
+///         instructions: vec!["Verser le Pastis".to_string(), "Ajouter l'eau".to_string()],
 ///     },
-/// }; 
-#[derive(Debug, Serialize, Deserialize)]
+/// };
+/// assert_eq!(master.pnj.caracter.hp, 120);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Master {
+    pub id: String,
     pub pnj: Pnj,
     pub badge: Badge,
-    pub attacks: Vec<String>,
+    pub attacks: Vec<Attack>,
     pub recipe: Recipe,
 }
 
@@ -1,23 +1,42 @@
-use crate::models::{aptitude::Aptitude, badge::Badge, ingredient::Ingredient};
+use crate::models::inventory::{InventoryStack, MAX_STACK_SIZE};
+use crate::models::level::LevelStep;
+use crate::models::recipe::Recipe;
+use crate::models::{aptitude::Aptitude, badge::Badge};
 use super::caracter::Caracter;
 use serde_derive::{Deserialize, Serialize};
 
+/// Bankroll d'un nouveau joueur, de quoi acheter ses premiers ingrédients
+pub const PLAYER_START_BANKROLL: i32 = 50;
+
 /// Représente un joueur dans le jeu
+/// `badge` est le badge de départ choisi à la création, `badges_won` la collection
+/// des badges gagnés contre les maîtres, qui ouvrent les arènes suivantes.
+/// `caracter.hp` et `caracter.pp` sont les HP et PP actuels du joueur, `max_hp` et `max_pp`
+/// les maximums de son niveau.
 /// 
 /// # Exemple
 /// 
 /// ```
-/// let player = Player::new("Alice", 100);
-/// assert_eq!(player.name(), "Alice");
+/// let badge = Badge { name: "Badge 1".to_string(), features: vec![], effects: vec![] };
+/// let inventory = vec![InventoryStack { ingredient_id: "Pastis".to_string(), quantity: 2 }];
+/// let player = Player::new("Alice", "Elfe", badge, inventory, vec![]);
+/// assert_eq!(player.caracter.name, "Alice");
+/// assert_eq!(player.ingredient_quantity("Pastis"), 2);
 /// ```
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Player {
     pub caracter: Caracter,
     pub badge: Badge,
-    pub inventory: Vec<Ingredient>,
+    pub inventory: Vec<InventoryStack>,
     pub level: u32,
+    pub max_hp: i32,
+    pub max_pp: i32,
+    #[serde(default)]
+    pub xp: u32,
     pub reputation: u32,
     pub aptitudes: Vec<Aptitude>,
+    #[serde(default)]
+    pub badges_won: Vec<Badge>,
 }
 
 impl Player {
@@ -34,14 +53,140 @@ impl Player {
     /// # Returns
     /// 
     /// Un nouveau `Player` avec les valeurs spécifiées
-    pub fn new(name: &str, style: &str, badge: Badge, inventory: Vec<Ingredient>, aptitudes: Vec<Aptitude>) -> Self {
+    pub fn new(name: &str, style: &str, badge: Badge, inventory: Vec<InventoryStack>, aptitudes: Vec<Aptitude>) -> Self {
         Self {
-            caracter: Caracter::new(name, style, 100, 50, 0),
+            caracter: Caracter::new(name, style, 100, 50, PLAYER_START_BANKROLL),
             level: 1,
+            max_hp: 100,
+            max_pp: 50,
+            xp: 0,
             reputation: 0,
             inventory,
             badge,
             aptitudes,
+            badges_won: Vec::new(),
         }
     }
-}
\ No newline at end of file
+
+    /// Passe le joueur au niveau d'un palier : ses HP et PP maximum deviennent ceux du palier.
+    /// Ses HP et PP actuels ne sont pas remplis, seulement ramenés sous les nouveaux maximums.
+    ///
+    /// # Arguments
+    /// * `step` - Le palier de la courbe de niveaux atteint par le joueur.
+    pub fn apply_level(&mut self, step: &LevelStep) {
+        self.level = step.level;
+        self.max_hp = step.max_hp;
+        self.max_pp = step.max_pp;
+        self.caracter.hp = self.caracter.hp.min(self.max_hp);
+        self.caracter.pp = self.caracter.pp.min(self.max_pp);
+    }
+
+    /// Quantité d'un ingrédient dans l'inventaire
+    ///
+    /// # Arguments
+    /// * `ingredient_id` - Le nom de l'ingrédient dans le catalogue.
+    pub fn ingredient_quantity(&self, ingredient_id: &str) -> u32 {
+        self.inventory
+            .iter()
+            .find(|stack| stack.ingredient_id == ingredient_id)
+            .map_or(0, |stack| stack.quantity)
+    }
+
+    /// Ajoute des unités d'un ingrédient sans dépasser `MAX_STACK_SIZE`.
+    /// Retourne la quantité réellement ajoutée.
+    ///
+    /// # Arguments
+    /// * `ingredient_id` - Le nom de l'ingrédient dans le catalogue.
+    /// * `quantity` - La quantité à ajouter.
+    pub fn add_ingredient(&mut self, ingredient_id: &str, quantity: u32) -> u32 {
+        let position = match self
+            .inventory
+            .iter()
+            .position(|stack| stack.ingredient_id == ingredient_id)
+        {
+            Some(position) => position,
+            None => {
+                self.inventory.push(InventoryStack {
+                    ingredient_id: ingredient_id.to_string(),
+                    quantity: 0,
+                });
+                self.inventory.len() - 1
+            }
+        };
+
+        let stack = &mut self.inventory[position];
+        let added = quantity.min(MAX_STACK_SIZE.saturating_sub(stack.quantity));
+        stack.quantity += added;
+        if stack.quantity == 0 {
+            self.inventory.remove(position);
+        }
+        added
+    }
+
+    /// Retire des unités d'un ingrédient. Rien n'est retiré s'il n'y en a pas assez.
+    /// Une pile vide disparaît de l'inventaire.
+    ///
+    /// # Arguments
+    /// * `ingredient_id` - Le nom de l'ingrédient dans le catalogue.
+    /// * `quantity` - La quantité à retirer.
+    pub fn remove_ingredient(&mut self, ingredient_id: &str, quantity: u32) -> bool {
+        let Some(position) = self
+            .inventory
+            .iter()
+            .position(|stack| stack.ingredient_id == ingredient_id && stack.quantity >= quantity)
+        else {
+            return false;
+        };
+
+        self.inventory[position].quantity -= quantity;
+        if self.inventory[position].quantity == 0 {
+            self.inventory.remove(position);
+        }
+        true
+    }
+
+    /// Vrai si l'inventaire contient toutes les quantités demandées par la recette
+    pub fn can_craft(&self, recipe: &Recipe) -> bool {
+        recipe
+            .ingredients
+            .iter()
+            .all(|item| self.ingredient_quantity(&item.ingredient) >= item.quantity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stack(name: &str, quantity: u32) -> InventoryStack {
+        InventoryStack {
+            ingredient_id: name.to_string(),
+            quantity,
+        }
+    }
+
+    /// Test des piles de l'inventaire
+    /// Une pile ne dépasse pas `MAX_STACK_SIZE` et disparaît une fois vide.
+    #[test]
+    fn test_inventory_stacks_are_capped_and_removed_when_empty() {
+        let badge = Badge {
+            name: "Badge 1".to_string(),
+            features: vec![],
+            effects: vec![],
+        };
+        let mut player = Player::new(
+            "Elendil",
+            "Elfe",
+            badge,
+            vec![stack("Pastis", 2), stack("Vodka", 1)],
+            vec![],
+        );
+
+        assert_eq!(player.add_ingredient("Pastis", MAX_STACK_SIZE), MAX_STACK_SIZE - 2);
+        assert_eq!(player.ingredient_quantity("Pastis"), MAX_STACK_SIZE);
+        assert!(!player.remove_ingredient("Vodka", 2));
+        assert!(player.remove_ingredient("Vodka", 1));
+        assert_eq!(player.ingredient_quantity("Vodka"), 0);
+        assert!(player.inventory.iter().all(|stack| stack.ingredient_id != "Vodka"));
+    }
+}
@@ -3,11 +3,12 @@ use crate::models::ingredient::Ingredient;
 use super::pnj::Pnj;
 use serde_derive::{Deserialize, Serialize};
 
-
-#[derive(Debug, Serialize, Deserialize)]
+/// Représente un marchand, un PNJ qui vend des ingrédients au joueur.
+/// Les prix de son stock sont payés avec le bankroll du joueur.
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Trader {
     pub pnj: Pnj,
-    pub ingredients : Vec<Ingredient>
+    pub ingredients: Vec<Ingredient>,
 }
 
 impl Trader {
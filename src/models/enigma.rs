@@ -0,0 +1,45 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Représente une énigme posée par un videur à l'entrée d'une arène.
+/// Le joueur choisit parmi la bonne réponse et les distracteurs, présentés dans un ordre
+/// aléatoire. Un indice optionnel peut être demandé avant de répondre.
+/// Quand le videur demande une réponse tapée, la réponse et les `accepted_answers`
+/// (synonymes) sont toutes acceptées.
+///
+/// # Exemple
+/// ```
+/// let enigma = Enigma {
+///     question: "Qu'est-ce qui a des clés mais n'ouvre aucune porte ?".to_string(),
+///     answer: "Un piano".to_string(),
+///     distractors: vec!["Un gardien".to_string(), "Une serrure".to_string()],
+///     hint: Some("On en joue".to_string()),
+///     accepted_answers: vec!["Un clavecin".to_string()],
+/// };
+/// assert_eq!(enigma.options().len(), 3);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Enigma {
+    pub question: String,
+    pub answer: String,
+    pub distractors: Vec<String>,
+    #[serde(default)]
+    pub hint: Option<String>,
+    #[serde(default)]
+    pub accepted_answers: Vec<String>,
+}
+
+impl Enigma {
+    /// Retourne toutes les réponses proposées : la bonne réponse suivie des distracteurs
+    pub fn options(&self) -> Vec<String> {
+        std::iter::once(self.answer.clone())
+            .chain(self.distractors.iter().cloned())
+            .collect()
+    }
+
+    /// Retourne toutes les réponses acceptées en saisie libre : la bonne réponse et ses synonymes
+    pub fn all_accepted_answers(&self) -> Vec<String> {
+        std::iter::once(self.answer.clone())
+            .chain(self.accepted_answers.iter().cloned())
+            .collect()
+    }
+}
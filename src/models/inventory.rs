@@ -0,0 +1,18 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Nombre maximal d'unités d'un même ingrédient dans l'inventaire
+pub const MAX_STACK_SIZE: u32 = 20;
+
+/// Pile d'un ingrédient dans l'inventaire du joueur.
+/// L'ingrédient est désigné par son nom dans le catalogue (`ingredients.json`).
+///
+/// # Exemple
+/// ```
+/// let stack = InventoryStack { ingredient_id: "Pastis".to_string(), quantity: 3 };
+/// assert!(stack.quantity <= MAX_STACK_SIZE);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct InventoryStack {
+    pub ingredient_id: String,
+    pub quantity: u32,
+}
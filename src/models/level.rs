@@ -0,0 +1,32 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Palier de la courbe de niveaux, défini dans `levels.json`.
+/// Un joueur atteint ce niveau dès qu'il a cumulé `xp` points d'expérience ;
+/// ses HP et PP maximum et son nombre d'emplacements d'aptitudes sont alors ceux du palier.
+///
+/// # Exemple
+/// ```
+/// let step = LevelStep { level: 2, xp: 50, max_hp: 110, max_pp: 55, aptitude_slots: 1 };
+/// assert_eq!(level_for_xp(&[step.clone()], 60), Some(&step));
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LevelStep {
+    pub level: u32,
+    pub xp: u32,
+    pub max_hp: i32,
+    pub max_pp: i32,
+    pub aptitude_slots: usize,
+}
+
+/// Retourne le palier atteint avec une quantité d'expérience : le plus haut palier
+/// dont l'expérience requise est atteinte.
+///
+/// # Arguments
+/// * `curve` - La courbe de niveaux.
+/// * `xp` - L'expérience cumulée du joueur.
+pub fn level_for_xp(curve: &[LevelStep], xp: u32) -> Option<&LevelStep> {
+    curve
+        .iter()
+        .filter(|step| step.xp <= xp)
+        .max_by_key(|step| step.level)
+}
@@ -1,12 +1,42 @@
 use serde_derive::{Deserialize, Serialize};
 
-use crate::models::ingredient::Ingredient;
+/// Ligne d'une recette : un ingrédient du catalogue, sa quantité et son unité.
+/// La quantité est le nombre d'unités retirées de l'inventaire quand le cocktail est réussi.
+///
+/// # Exemple
+/// ```
+/// let item = RecipeItem { ingredient: "Pastis".to_string(), quantity: 2, unit: "cl".to_string() };
+/// assert_eq!(item.to_string(), "2 cl de Pastis");
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RecipeItem {
+    pub ingredient: String,
+    #[serde(default = "default_quantity")]
+    pub quantity: u32,
+    #[serde(default)]
+    pub unit: String,
+}
+
+// Quantité d'une ligne de recette qui n'en précise pas
+fn default_quantity() -> u32 {
+    1
+}
+
+impl std::fmt::Display for RecipeItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.unit.is_empty() {
+            write!(f, "{} x {}", self.quantity, self.ingredient)
+        } else {
+            write!(f, "{} {} de {}", self.quantity, self.unit, self.ingredient)
+        }
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 
 // Recette avec ingrédients et instructions
 pub struct Recipe {
-    pub ingredients: Vec<Ingredient>,
+    pub ingredients: Vec<RecipeItem>,
     pub instructions: Vec<String>,
 }
 
@@ -0,0 +1,66 @@
+use crate::models::caracter::player::Player;
+use serde_derive::{Deserialize, Serialize};
+
+/// Version actuelle du format de sauvegarde.
+/// Elle doit être incrémentée à chaque changement incompatible, avec une migration associée.
+pub const SAVE_FORMAT_VERSION: u32 = 3;
+
+/// Représente une sauvegarde complète d'un slot.
+/// Elle contient le joueur, sa progression dans la campagne et ses réglages.
+///
+/// # Exemple
+/// ```
+/// let save = SaveGame::new(player);
+/// assert_eq!(save.format_version, SAVE_FORMAT_VERSION);
+/// assert!(save.progress.beaten_arenas.is_empty());
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SaveGame {
+    pub format_version: u32,
+    pub player: Player,
+    #[serde(default)]
+    pub progress: CampaignProgress,
+    #[serde(default)]
+    pub settings: GameSettings,
+}
+
+/// Progression du joueur dans la campagne
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CampaignProgress {
+    pub beaten_arenas: Vec<String>,
+    pub last_arena: Option<String>,
+    /// Graine du hasard de la dernière partie jouée, reprise au chargement du slot
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+/// Réglages propres à une sauvegarde
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GameSettings {
+    pub music_volume: f32,
+    pub fullscreen: bool,
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            music_volume: 0.5,
+            fullscreen: false,
+        }
+    }
+}
+
+impl SaveGame {
+    /// Crée une nouvelle sauvegarde au format actuel pour un joueur
+    ///
+    /// # Arguments
+    /// * `player` - Le joueur à sauvegarder.
+    pub fn new(player: Player) -> Self {
+        Self {
+            format_version: SAVE_FORMAT_VERSION,
+            player,
+            progress: CampaignProgress::default(),
+            settings: GameSettings::default(),
+        }
+    }
+}
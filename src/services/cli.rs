@@ -0,0 +1,216 @@
+use crate::services::session::SLOT_COUNT;
+
+/// Interface utilisée pour jouer
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Frontend {
+    /// Fenêtre graphique Bevy
+    #[default]
+    Bevy,
+    /// Interface terminal ratatui
+    Tui,
+    /// Sans affichage interactif : les choix sont lus ligne par ligne sur l'entrée standard
+    Headless,
+}
+
+/// Aide affichée avec `--help` ou après une option invalide
+pub const USAGE: &str = "Usage: rpg_rust [options]
+
+Options:
+  --assets <dossier>       Dossier des fichiers de donnees du jeu (defaut: assets)
+  --saves <dossier>        Dossier des sauvegardes des slots (defaut: save)
+  --frontend <interface>   Interface de jeu : bevy, tui ou headless (defaut: bevy)
+  --tui                    Raccourci pour --frontend tui
+  --headless               Raccourci pour --frontend headless
+  --slot <1-3>             Charge directement le joueur de ce slot
+  --arena <id>             Entre directement dans le combat de cette arene (tests)
+  --seed <nombre>          Graine aleatoire de la partie
+  --record <fichier>       Enregistre les entrees du joueur dans un fichier de rejeu (bevy ou tui)
+  --replay <fichier>       Rejoue une session enregistree : une session bevy avec bevy,
+                           une session tui avec headless
+  --validate-content       Verifie les donnees du jeu sans lancer la partie
+  -h, --help               Affiche cette aide";
+
+/// Options de lancement du jeu, lues sur la ligne de commande
+#[derive(Debug, Clone, PartialEq)]
+pub struct CliArgs {
+    pub asset_dir: String,
+    pub save_dir: String,
+    pub frontend: Frontend,
+    /// Index du slot à charger, de 0 à 2
+    pub slot: Option<usize>,
+    /// Id de l'arène dans laquelle commencer
+    pub arena: Option<String>,
+    pub seed: Option<u64>,
+    /// Fichier où enregistrer les entrées du joueur
+    pub record: Option<String>,
+    /// Fichier de la session à rejouer
+    pub replay: Option<String>,
+    pub validate_content: bool,
+    pub help: bool,
+}
+
+impl Default for CliArgs {
+    fn default() -> Self {
+        Self {
+            asset_dir: "assets".to_string(),
+            save_dir: "save".to_string(),
+            frontend: Frontend::default(),
+            slot: None,
+            arena: None,
+            seed: None,
+            record: None,
+            replay: None,
+            validate_content: false,
+            help: false,
+        }
+    }
+}
+
+impl CliArgs {
+    /// Lit les arguments de la ligne de commande, sans le nom du programme.
+    /// Les valeurs s'écrivent `--option valeur` ou `--option=valeur`.
+    /// Retourne un message si une option est inconnue ou si sa valeur est invalide,
+    /// ou si un rejeu est demandé avec l'interface terminal.
+    ///
+    /// # Arguments
+    /// * `args` - Les arguments passés au programme.
+    ///
+    /// # Exemple
+    /// ```
+    /// let args = CliArgs::parse(["--tui", "--slot", "2"].map(String::from)).unwrap();
+    /// assert_eq!(args.frontend, Frontend::Tui);
+    /// assert_eq!(args.slot, Some(1));
+    /// ```
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let (name, inline_value) = match arg.split_once('=') {
+                Some((name, value)) => (name.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            let mut value = || {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("L'option {} attend une valeur", name))
+            };
+
+            match name.as_str() {
+                "--assets" => parsed.asset_dir = value()?,
+                "--saves" => parsed.save_dir = value()?,
+                "--frontend" => parsed.frontend = parse_frontend(&value()?)?,
+                "--tui" => parsed.frontend = Frontend::Tui,
+                "--headless" => parsed.frontend = Frontend::Headless,
+                "--slot" => parsed.slot = Some(parse_slot(&value()?)?),
+                "--arena" => parsed.arena = Some(value()?),
+                "--seed" => {
+                    let seed = value()?;
+                    parsed.seed = Some(
+                        seed.parse()
+                            .map_err(|_| format!("Graine invalide : {}", seed))?,
+                    );
+                }
+                "--record" => parsed.record = Some(value()?),
+                "--replay" => parsed.replay = Some(value()?),
+                "--validate-content" => parsed.validate_content = true,
+                "-h" | "--help" => parsed.help = true,
+                _ => return Err(format!("Option inconnue : {}", name)),
+            }
+        }
+
+        if parsed.replay.is_some() && parsed.frontend == Frontend::Tui {
+            return Err("Le rejeu se lance avec l'interface bevy ou headless".to_string());
+        }
+        if parsed.replay.is_some() && parsed.record.is_some() {
+            return Err("Une session rejouee ne peut pas etre enregistree".to_string());
+        }
+        Ok(parsed)
+    }
+}
+
+// Interface demandée avec `--frontend`
+fn parse_frontend(value: &str) -> Result<Frontend, String> {
+    match value {
+        "bevy" => Ok(Frontend::Bevy),
+        "tui" => Ok(Frontend::Tui),
+        "headless" => Ok(Frontend::Headless),
+        _ => Err(format!(
+            "Interface inconnue : {} (bevy, tui ou headless)",
+            value
+        )),
+    }
+}
+
+// Numéro de slot affiché au joueur (de 1 à `SLOT_COUNT`), converti en index
+fn parse_slot(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(slot) if (1..=SLOT_COUNT).contains(&slot) => Ok(slot - 1),
+        _ => Err(format!("Slot invalide : {} (de 1 à {})", value, SLOT_COUNT)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<CliArgs, String> {
+        CliArgs::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    /// Test des options par défaut
+    /// Sans argument, le jeu se lance avec Bevy sur les dossiers habituels.
+    #[test]
+    fn test_parse_defaults() {
+        let args = parse(&[]).unwrap();
+
+        assert_eq!(args, CliArgs::default());
+        assert_eq!(args.asset_dir, "assets");
+        assert_eq!(args.save_dir, "save");
+        assert_eq!(args.frontend, Frontend::Bevy);
+    }
+
+    /// Test de toutes les options
+    /// Les valeurs sont acceptées séparées par un espace ou par `=`.
+    #[test]
+    fn test_parse_all_options() {
+        let args = parse(&[
+            "--assets=packs/noel",
+            "--saves",
+            "/tmp/saves",
+            "--frontend",
+            "headless",
+            "--slot",
+            "3",
+            "--arena",
+            "pastis",
+            "--seed=42",
+            "--record",
+            "bug.json",
+        ])
+        .unwrap();
+
+        assert_eq!(args.asset_dir, "packs/noel");
+        assert_eq!(args.save_dir, "/tmp/saves");
+        assert_eq!(args.frontend, Frontend::Headless);
+        assert_eq!(args.slot, Some(2));
+        assert_eq!(args.arena.as_deref(), Some("pastis"));
+        assert_eq!(args.seed, Some(42));
+        assert_eq!(args.record.as_deref(), Some("bug.json"));
+    }
+
+    /// Test des options invalides
+    /// Une option inconnue, une valeur manquante ou hors limites est refusée avec un message.
+    #[test]
+    fn test_parse_rejects_invalid_options() {
+        assert!(parse(&["--debug"]).is_err());
+        assert!(parse(&["--assets"]).is_err());
+        assert!(parse(&["--slot", "4"]).is_err());
+        assert!(parse(&["--slot", "0"]).is_err());
+        assert!(parse(&["--frontend", "web"]).is_err());
+        assert!(parse(&["--seed", "abc"]).is_err());
+        assert!(parse(&["--tui", "--replay", "bug.json"]).is_err());
+        assert!(parse(&["--replay", "bug.jsonl", "--record", "copie.jsonl"]).is_err());
+    }
+}
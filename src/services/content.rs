@@ -0,0 +1,126 @@
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+
+use crate::models::aptitude::Aptitude;
+use crate::models::arena::Arena;
+use crate::models::badge::Badge;
+use crate::models::caracter::bouncer::Bouncer;
+use crate::models::caracter::client::Client;
+use crate::models::caracter::master::Master;
+use crate::models::caracter::trader::Trader;
+use crate::models::ingredient::Ingredient;
+use crate::models::level::LevelStep;
+use crate::services::json_loader::{JsonLoader, LoadError};
+
+/// Fichiers de données du jeu, relatifs au dossier des assets
+pub const ARENAS_FILE: &str = "caracters/arena.json";
+pub const MASTERS_FILE: &str = "caracters/pnj/masters.json";
+pub const BOUNCERS_FILE: &str = "caracters/pnj/bouncer.json";
+pub const CLIENTS_FILE: &str = "caracters/pnj/clients.json";
+pub const TRADERS_FILE: &str = "caracters/pnj/traders.json";
+pub const APTITUDES_FILE: &str = "caracters/aptitudes.json";
+pub const BADGES_FILE: &str = "badges.json";
+pub const INGREDIENTS_FILE: &str = "ingredients.json";
+pub const LEVELS_FILE: &str = "levels.json";
+
+/// Contenu du jeu chargé une seule fois au démarrage depuis les fichiers JSON.
+/// Tous les écrans et le moteur de jeu lisent leurs données ici au lieu de relire le disque.
+///
+/// # Exemple
+/// ```
+/// let content = GameContent::load("assets").unwrap();
+/// assert!(!content.masters.is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct GameContent {
+    pub arenas: Vec<Arena>,
+    pub masters: Vec<Master>,
+    pub bouncers: Vec<Bouncer>,
+    pub clients: Vec<Client>,
+    pub traders: Vec<Trader>,
+    pub aptitudes: Vec<Aptitude>,
+    pub badges: Vec<Badge>,
+    pub ingredients: Vec<Ingredient>,
+    pub levels: Vec<LevelStep>,
+}
+
+impl GameContent {
+    /// Charge tout le contenu du jeu depuis un dossier d'assets.
+    /// Tous les fichiers sont lus même si l'un d'eux échoue, pour retourner
+    /// la liste complète des erreurs.
+    ///
+    /// # Arguments
+    /// * `asset_dir` - Le dossier qui contient les fichiers de données (ex : `assets`).
+    pub fn load(asset_dir: &str) -> Result<Self, Vec<LoadError>> {
+        let mut errors = Vec::new();
+
+        let content = Self {
+            arenas: load_or_collect(asset_dir, ARENAS_FILE, &mut errors),
+            masters: load_or_collect(asset_dir, MASTERS_FILE, &mut errors),
+            bouncers: load_or_collect(asset_dir, BOUNCERS_FILE, &mut errors),
+            clients: load_or_collect(asset_dir, CLIENTS_FILE, &mut errors),
+            traders: load_or_collect(asset_dir, TRADERS_FILE, &mut errors),
+            aptitudes: load_or_collect(asset_dir, APTITUDES_FILE, &mut errors),
+            badges: load_or_collect(asset_dir, BADGES_FILE, &mut errors),
+            ingredients: load_or_collect(asset_dir, INGREDIENTS_FILE, &mut errors),
+            levels: load_or_collect(asset_dir, LEVELS_FILE, &mut errors),
+        };
+
+        if errors.is_empty() {
+            Ok(content)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+// Charge une liste depuis un fichier du dossier d'assets, en gardant l'erreur éventuelle
+fn load_or_collect<T: DeserializeOwned>(
+    asset_dir: &str,
+    file: &str,
+    errors: &mut Vec<LoadError>,
+) -> Vec<T> {
+    let path = Path::new(asset_dir).join(file);
+    match JsonLoader::load_json(&path.to_string_lossy()) {
+        Ok(items) => items,
+        Err(e) => {
+            errors.push(e);
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test de chargement des assets livrés avec le jeu
+    /// Tous les fichiers de données doivent être lisibles par leurs modèles.
+    #[test]
+    fn test_load_bundled_assets() {
+        let content = GameContent::load("assets").unwrap();
+
+        assert_eq!(content.arenas.len(), content.masters.len());
+        assert!(!content.bouncers.is_empty());
+        assert!(!content.clients.is_empty());
+        assert!(!content.traders.is_empty());
+        assert!(content.traders.iter().all(|trader| !trader.ingredients.is_empty()));
+        assert!(!content.aptitudes.is_empty());
+        assert!(!content.badges.is_empty());
+        assert!(!content.ingredients.is_empty());
+        assert_eq!(content.levels[0].level, 1);
+    }
+
+    /// Test de chargement d'un dossier d'assets vide
+    /// Une erreur doit être retournée pour chaque fichier manquant.
+    #[test]
+    fn test_load_reports_every_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let errors = GameContent::load(dir.path().to_str().unwrap()).unwrap_err();
+
+        assert_eq!(errors.len(), 9);
+        assert!(errors.iter().all(|e| matches!(e, LoadError::NotFound { .. })));
+    }
+}
@@ -0,0 +1,395 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::models::badge::{Badge, BadgeEffect};
+use crate::models::caracter::bouncer::AnswerMode;
+use crate::models::inventory::MAX_STACK_SIZE;
+use crate::models::recipe::Recipe;
+use crate::services::content::GameContent;
+use crate::services::engine::answer::normalize_answer;
+use crate::services::json_loader::LoadError;
+
+/// Rapport de validation du contenu du jeu.
+/// Les erreurs empêchent le jeu de fonctionner correctement, les avertissements
+/// signalent du contenu suspect mais jouable.
+#[derive(Debug, Default)]
+pub struct ValidationReport {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl ValidationReport {
+    /// Vrai si aucune erreur n'a été trouvée
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    // Ajoute une erreur au rapport
+    fn error(&mut self, message: String) {
+        self.errors.push(message);
+    }
+
+    // Ajoute un avertissement au rapport
+    fn warning(&mut self, message: String) {
+        self.warnings.push(message);
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for error in &self.errors {
+            writeln!(f, "[ERREUR] {}", error)?;
+        }
+        for warning in &self.warnings {
+            writeln!(f, "[ATTENTION] {}", warning)?;
+        }
+        write!(
+            f,
+            "{} erreur(s), {} avertissement(s)",
+            self.errors.len(),
+            self.warnings.len()
+        )
+    }
+}
+
+/// Charge tous les fichiers de données d'un dossier d'assets et vérifie leur cohérence.
+/// Les fichiers illisibles sont reportés comme erreurs, sans vérifier les références.
+///
+/// # Arguments
+/// * `asset_dir` - Le dossier qui contient les fichiers de données.
+pub fn validate_asset_dir(asset_dir: &str) -> ValidationReport {
+    match GameContent::load(asset_dir) {
+        Ok(content) => validate_content(&content),
+        Err(errors) => ValidationReport {
+            errors: errors.iter().map(LoadError::to_string).collect(),
+            warnings: vec![],
+        },
+    }
+}
+
+/// Vérifie les références croisées du contenu du jeu :
+/// - chaque arène a un maître et un videur existants ;
+/// - les badges demandés par les arènes sont donnés par un maître ;
+/// - les ingrédients des recettes, des commandes et des marchands existent dans le catalogue ;
+/// - les recettes ont des instructions et des quantités réalisables ;
+/// - les énigmes des videurs ont une réponse, tapable au clavier en saisie libre ;
+/// - la courbe de niveaux et les effets des badges sont cohérents.
+///
+/// # Arguments
+/// * `content` - Le contenu du jeu déjà chargé.
+pub fn validate_content(content: &GameContent) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    let catalog: HashSet<&str> = content
+        .ingredients
+        .iter()
+        .map(|ingredient| ingredient.name.as_str())
+        .collect();
+
+    // Les arènes désignent leur maître, leur videur et les arènes requises par id
+    let arena_ids = unique_ids(&mut report, "Arene", content.arenas.iter().map(|a| a.id.as_str()));
+    let master_ids = unique_ids(&mut report, "Maitre", content.masters.iter().map(|m| m.id.as_str()));
+    let bouncer_ids = unique_ids(&mut report, "Videur", content.bouncers.iter().map(|b| b.id.as_str()));
+
+    let master_badges = unique_ids(
+        &mut report,
+        "Badge",
+        content.masters.iter().map(|m| m.badge.name.as_str()),
+    );
+
+    for arena in &content.arenas {
+        for badge in &arena.required_badges {
+            if !master_badges.contains(badge.as_str()) {
+                report.error(format!("Arene {} : badge requis {} donne par aucun maitre", arena.id, badge));
+            }
+        }
+        if !master_ids.contains(arena.master_id.as_str()) {
+            report.error(format!("Arene {} : maitre {} introuvable", arena.id, arena.master_id));
+        }
+        if !bouncer_ids.contains(arena.bouncer_id.as_str()) {
+            report.error(format!("Arene {} : videur {} introuvable", arena.id, arena.bouncer_id));
+        }
+        for required in &arena.unlock_requirements {
+            if !arena_ids.contains(required.as_str()) {
+                report.error(format!("Arene {} : arene requise {} introuvable", arena.id, required));
+            } else if *required == arena.id {
+                report.error(format!("Arene {} : ne peut pas se requerir elle-meme", arena.id));
+            }
+        }
+    }
+    if content.arenas.iter().filter(|arena| arena.championship).count() > 1 {
+        report.warning("Plusieurs arenes de championnat".to_string());
+    }
+    for master in &content.masters {
+        if !content.arenas.iter().any(|arena| arena.master_id == master.id) {
+            report.warning(format!("Maitre {} : aucune arene", master.id));
+        }
+    }
+
+    for master in &content.masters {
+        let name = &master.pnj.caracter.name;
+        check_recipe(&mut report, &catalog, &format!("Recette de {}", name), &master.recipe);
+        check_badge_effects(&mut report, content, &master.badge);
+        if master.attacks.is_empty() {
+            report.warning(format!("Maitre {} : aucune attaque", name));
+        }
+        for attack in &master.attacks {
+            if !(0.0..=1.0).contains(&attack.accuracy) {
+                report.error(format!(
+                    "Maitre {} : precision de {} hors de [0, 1] ({})",
+                    name, attack.name, attack.accuracy
+                ));
+            }
+        }
+    }
+
+    if content.bouncers.is_empty() {
+        report.error("Aucun videur pour garder les arenes".to_string());
+    }
+    for bouncer in &content.bouncers {
+        if bouncer.enigmas.is_empty() {
+            report.error(format!("Videur {} : aucune enigme", bouncer.id));
+        }
+        for enigma in &bouncer.enigmas {
+            if enigma.answer.trim().is_empty() {
+                report.error(format!("Videur {} : enigme sans reponse ({})", bouncer.id, enigma.question));
+            }
+            if bouncer.answer_mode == AnswerMode::FreeText {
+                // En saisie libre, chaque réponse acceptée doit rester tapable une fois normalisée
+                for accepted in enigma.all_accepted_answers() {
+                    if normalize_answer(&accepted).is_empty() {
+                        report.error(format!(
+                            "Videur {} : reponse acceptee impossible a taper ({})",
+                            bouncer.id, enigma.question
+                        ));
+                    }
+                }
+            } else if enigma.distractors.is_empty() {
+                report.warning(format!("Videur {} : enigme sans distracteur ({})", bouncer.id, enigma.question));
+            }
+            if enigma.distractors.contains(&enigma.answer) {
+                report.error(format!(
+                    "Videur {} : la reponse est aussi un distracteur ({})",
+                    bouncer.id, enigma.question
+                ));
+            }
+        }
+    }
+
+    for trader in &content.traders {
+        let name = &trader.pnj.caracter.name;
+        check_ingredients(
+            &mut report,
+            &catalog,
+            &format!("Marchand {}", name),
+            trader.ingredients.iter().map(|ingredient| ingredient.name.as_str()),
+        );
+        if trader.ingredients.is_empty() {
+            report.warning(format!("Marchand {} : stock vide", name));
+        }
+    }
+
+    for client in &content.clients {
+        let name = &client.pnj.caracter.name;
+        if client.tips < 0 {
+            report.error(format!("Client {} : pourboire negatif", name));
+        }
+        check_recipe(&mut report, &catalog, &format!("Commande de {}", name), &client.order);
+    }
+
+    for aptitude in &content.aptitudes {
+        if aptitude.pp < 0 {
+            report.error(format!("Aptitude {} : cout en PP negatif", aptitude.name));
+        }
+        if aptitude.effect.is_none() {
+            report.warning(format!("Aptitude {} : aucun effet en combat", aptitude.name));
+        }
+    }
+
+    check_level_curve(&mut report, content);
+
+    let mut badge_names = HashSet::new();
+    for badge in &content.badges {
+        if !badge_names.insert(badge.name.as_str()) {
+            report.error(format!("Badge {} : nom en double", badge.name));
+        }
+        check_badge_effects(&mut report, content, badge);
+    }
+
+    report
+}
+
+// Retourne les ids d'une liste en signalant les doublons
+fn unique_ids<'a>(
+    report: &mut ValidationReport,
+    kind: &str,
+    ids: impl Iterator<Item = &'a str>,
+) -> HashSet<&'a str> {
+    let mut unique = HashSet::new();
+    for id in ids {
+        if !unique.insert(id) {
+            report.error(format!("{} {} : id en double", kind, id));
+        }
+    }
+    unique
+}
+
+// Vérifie les effets d'un badge : pourcentages dans [1, 100] et thème d'arène existant
+fn check_badge_effects(report: &mut ValidationReport, content: &GameContent, badge: &Badge) {
+    for effect in &badge.effects {
+        let percent = match effect {
+            BadgeEffect::DamageBonus { theme, percent } => {
+                if let Some(theme) = theme {
+                    if !content.arenas.iter().any(|arena| arena.theme.eq_ignore_ascii_case(theme)) {
+                        report.warning(format!("Badge {} : aucune arene du theme {}", badge.name, theme));
+                    }
+                }
+                *percent
+            }
+            BadgeEffect::TraderDiscount { percent } => *percent,
+            BadgeEffect::IngredientHint => continue,
+        };
+        if !(1..=100).contains(&percent) {
+            report.error(format!(
+                "Badge {} : pourcentage hors de [1, 100] ({})",
+                badge.name, percent
+            ));
+        }
+    }
+}
+
+// Vérifie la courbe de niveaux : elle commence au niveau 1 sans expérience,
+// puis chaque palier demande plus d'expérience sans faire baisser les statistiques
+fn check_level_curve(report: &mut ValidationReport, content: &GameContent) {
+    let Some(first) = content.levels.first() else {
+        report.error("Courbe de niveaux vide".to_string());
+        return;
+    };
+    if first.level != 1 || first.xp != 0 {
+        report.error("Courbe de niveaux : le premier palier doit etre le niveau 1 a 0 XP".to_string());
+    }
+
+    for pair in content.levels.windows(2) {
+        let (previous, step) = (&pair[0], &pair[1]);
+        if step.level != previous.level + 1 || step.xp <= previous.xp {
+            report.error(format!(
+                "Niveau {} : doit suivre le niveau {} avec plus d'XP",
+                step.level, previous.level
+            ));
+        }
+        if step.max_hp < previous.max_hp
+            || step.max_pp < previous.max_pp
+            || step.aptitude_slots < previous.aptitude_slots
+        {
+            report.error(format!("Niveau {} : statistiques en baisse", step.level));
+        }
+    }
+
+    if let Some(last) = content.levels.last() {
+        if last.aptitude_slots > content.aptitudes.len() {
+            report.warning(format!(
+                "Niveau {} : {} emplacements d'aptitudes pour {} aptitudes",
+                last.level,
+                last.aptitude_slots,
+                content.aptitudes.len()
+            ));
+        }
+    }
+}
+
+// Vérifie qu'une recette est réalisable : ingrédients du catalogue en quantités valides
+// et au moins une instruction
+fn check_recipe(report: &mut ValidationReport, catalog: &HashSet<&str>, owner: &str, recipe: &Recipe) {
+    check_ingredients(
+        report,
+        catalog,
+        owner,
+        recipe.ingredients.iter().map(|item| item.ingredient.as_str()),
+    );
+    if recipe.ingredients.is_empty() {
+        report.error(format!("{} : aucun ingredient", owner));
+    }
+    for item in &recipe.ingredients {
+        if item.quantity == 0 || item.quantity > MAX_STACK_SIZE {
+            report.error(format!(
+                "{} : quantite de {} hors de [1, {}] ({})",
+                owner, item.ingredient, MAX_STACK_SIZE, item.quantity
+            ));
+        }
+    }
+    if recipe.instructions.is_empty() {
+        report.error(format!("{} : aucune instruction", owner));
+    }
+}
+
+// Vérifie que chaque ingrédient existe dans le catalogue
+fn check_ingredients<'a>(
+    report: &mut ValidationReport,
+    catalog: &HashSet<&str>,
+    owner: &str,
+    ingredients: impl Iterator<Item = &'a str>,
+) {
+    for ingredient in ingredients {
+        if !catalog.contains(ingredient) {
+            report.error(format!(
+                "{} : ingredient {} absent du catalogue",
+                owner, ingredient
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::arena::Arena;
+
+    /// Test de validation des assets livrés avec le jeu
+    /// Le contenu officiel ne doit contenir aucune erreur.
+    #[test]
+    fn test_bundled_assets_are_valid() {
+        let report = validate_asset_dir("assets");
+
+        assert!(report.is_valid(), "{}", report);
+    }
+
+    /// Test des références croisées cassées
+    /// Une arène qui référence un maître ou une arène inconnus et un ingrédient
+    /// absent du catalogue doivent être signalés.
+    #[test]
+    fn test_broken_references_are_reported() {
+        let mut content = GameContent::load("assets").unwrap();
+        content.arenas.push(Arena {
+            id: "rhum".to_string(),
+            name: "RHUM".to_string(),
+            theme: "ILES".to_string(),
+            master_id: "rhum_pirate".to_string(),
+            bouncer_id: "ragnar".to_string(),
+            difficulty: 4,
+            unlock_requirements: vec!["tequila".to_string()],
+            required_badges: vec!["Rhum Badge".to_string()],
+            hidden: false,
+            championship: false,
+        });
+        content.ingredients.retain(|ingredient| ingredient.name != "Pastis");
+        content.masters[1].recipe.instructions.clear();
+
+        let report = validate_content(&content);
+
+        assert!(!report.is_valid());
+        assert!(report.errors.iter().any(|e| e.contains("rhum_pirate")));
+        assert!(report.errors.iter().any(|e| e.contains("tequila")));
+        assert!(report.errors.iter().any(|e| e.contains("Rhum Badge")));
+        assert!(report.errors.iter().any(|e| e.contains("Pastis")));
+        assert!(report.errors.iter().any(|e| e.contains("aucune instruction")));
+    }
+
+    /// Test d'un dossier d'assets illisible
+    #[test]
+    fn test_missing_files_are_errors() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let report = validate_asset_dir(dir.path().to_str().unwrap());
+
+        assert!(!report.is_valid());
+    }
+}
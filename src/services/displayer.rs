@@ -1,6 +1,7 @@
-use crate::models::aptitude::Aptitude;
+use crate::services::tui::app::{TuiApp, TuiInput, TuiKey};
+use crate::services::tui::view::TuiView;
 use crossterm::{
-    event::{self, KeyCode},
+    event::{self, KeyCode, KeyEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -8,6 +9,8 @@ use ratatui::{prelude::*, widgets::*};
 use std::io::{self, stdout};
 
 /// Interface utilisateur en mode terminal
+/// Le terminal est restauré quand l'interface est détruite, y compris après une erreur,
+/// et un crochet de panique le restaure avant d'afficher le message de panique.
 pub(crate) struct Displayer {
     // Terminal configuré avec crossterm
     terminal: Terminal<CrosstermBackend<std::io::Stdout>>,
@@ -16,110 +19,136 @@ pub(crate) struct Displayer {
 impl Displayer {
     // On crée un nouveau terminal en mode plein écran
     pub fn new() -> io::Result<Self> {
+        install_panic_hook();
+
         // Configuration du terminal en mode raw
         enable_raw_mode()?;
         let mut stdout = stdout();
-        execute!(stdout, EnterAlternateScreen)?;
+        if let Err(e) = execute!(stdout, EnterAlternateScreen) {
+            let _ = restore_terminal();
+            return Err(e);
+        }
         let terminal = Terminal::new(CrosstermBackend::new(stdout))?;
         Ok(Self { terminal })
     }
 
-    // Affiche le menu principal et gère la navigation
-    pub fn show_menu(&mut self, aptitudes: &[Aptitude]) -> io::Result<()> {
-        loop {
-            // Rendu du menu principal
-
-            self.terminal.draw(|f| {
-                let size = f.size();
-                // Bloc avec titre et bordures
-
-                let block = Block::default()
-                    .title("RPG Rust - Menu Principal")
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Double);
-                // Options du menu
-
-                let menu_items = vec!["1. Nouvelle Partie", "2. Charger Partie", "3. Quitter"];
-
-                let paragraph = Paragraph::new(menu_items.join("\n"))
-                    .alignment(Alignment::Center)
-                    .block(block);
+    // On joue la partie dans le terminal jusqu'à ce que le joueur quitte
+    // Le terminal est restauré même si l'affichage échoue en cours de route
+    pub fn run(&mut self, mut app: TuiApp) -> io::Result<()> {
+        let result = self.game_loop(&mut app);
+        self.cleanup()?;
+        result
+    }
 
-                f.render_widget(paragraph, size);
-            })?;
+    // Boucle principale : on affiche l'écran courant puis on transmet les touches au jeu
+    fn game_loop(&mut self, app: &mut TuiApp) -> io::Result<()> {
+        while app.is_running() {
+            let view = app.view();
+            let selected = app.selected;
+            self.terminal.draw(|f| draw_view(f, &view, selected))?;
 
             // Gestion des événements clavier
-
             if event::poll(std::time::Duration::from_millis(100))? {
                 if let event::Event::Key(key) = event::read()? {
-                    match key.code {
-                        KeyCode::Char('1') => {
-                            println!("Demarrage d'une nouvelle partie...");
-                            break;
-                        }
-                        KeyCode::Char('2') => {
-                            println!("Chargement de la sauvegarde...");
-                            break;
-                        }
-                        KeyCode::Char('3') | KeyCode::Esc => {
-                            println!("Fermeture du jeu...");
-                            return Ok(());
-                        }
-                        _ => {}
+                    if key.kind != KeyEventKind::Press {
+                        continue;
                     }
+                    let key = match key.code {
+                        KeyCode::Up => TuiKey::Up,
+                        KeyCode::Down => TuiKey::Down,
+                        KeyCode::Enter => TuiKey::Enter,
+                        KeyCode::Backspace => TuiKey::Backspace,
+                        KeyCode::Esc => TuiKey::Esc,
+                        KeyCode::Char(c) => TuiKey::Char(c),
+                        _ => continue,
+                    };
+                    app.handle_input(TuiInput::Key(key));
                 }
             }
         }
         Ok(())
     }
-    // Affiche la liste des aptitudes disponibles
-
-    pub fn display_aptitudes(&mut self, aptitudes: &[Aptitude]) -> io::Result<()> {
-        loop {
-            // Rendu de la liste des aptitudes
 
-            self.terminal.draw(|f| {
-                let size = f.size();
-                // Bloc avec titre stylisé
+    // Nettoie et restaure le terminal
+    pub fn cleanup(&mut self) -> io::Result<()> {
+        self.terminal.show_cursor()?;
+        restore_terminal()
+    }
+}
 
-                let block = Block::default()
-                    .title("Aptitudes des Bartenders")
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded);
-                // Formatage des aptitudes
+impl Drop for Displayer {
+    // Garde-fou : le terminal est restauré même si la partie s'arrête sur une erreur
+    fn drop(&mut self) {
+        let _ = self.cleanup();
+    }
+}
 
-                let aptitude_list: Vec<String> = aptitudes
-                    .iter()
-                    .map(|apt| {
-                        format!(
-                            "🔹 {} - {} (PP: {}, Power: {})",
-                            apt.name, apt.description, apt.pp, apt.power
-                        )
-                    })
-                    .collect();
+// Sort du mode raw et de l'écran alternatif. Sans effet si le terminal est déjà restauré.
+fn restore_terminal() -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(stdout(), LeaveAlternateScreen)?;
+    Ok(())
+}
 
-                let paragraph = Paragraph::new(aptitude_list.join("\n\n"))
-                    .alignment(Alignment::Left)
-                    .block(block);
+// Restaure le terminal avant le message de panique, sinon il s'afficherait sur l'écran
+// alternatif en mode raw et serait effacé à la sortie
+fn install_panic_hook() {
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = restore_terminal();
+        previous_hook(info);
+    }));
+}
 
-                f.render_widget(paragraph, size);
-            })?;
-            // pour pouvoir sortir avec echa ou 'q'
-            if event::poll(std::time::Duration::from_millis(100))? {
-                if let event::Event::Key(key) = event::read()? {
-                    if key.code == KeyCode::Esc || key.code == KeyCode::Char('q') {
-                        break;
-                    }
-                }
-            }
-        }
-        Ok(())
+// Dessine un écran : le texte en haut, le champ de saisie puis la liste des choix
+fn draw_view(f: &mut Frame, view: &TuiView, selected: usize) {
+    let input_height = if view.input.is_some() { 3 } else { 0 };
+    let layout = Layout::vertical([
+        Constraint::Min(3),
+        Constraint::Length(input_height),
+        Constraint::Length(view.choices.len() as u16 + 2),
+        Constraint::Length(1),
+    ])
+    .split(f.area());
+
+    // Bloc avec titre et bordures
+    let block = Block::default()
+        .title(view.title.clone())
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double);
+    let paragraph = Paragraph::new(view.lines.join("\n"))
+        .wrap(Wrap { trim: false })
+        .block(block);
+    f.render_widget(paragraph, layout[0]);
+
+    if let Some(input) = &view.input {
+        let input = Paragraph::new(format!("{}_", input))
+            .block(Block::default().title("Saisie").borders(Borders::ALL));
+        f.render_widget(input, layout[1]);
     }
 
-    // Nettoie et restaure le terminal
-    pub fn cleanup(&mut self) -> io::Result<()> {
-        disable_raw_mode()?;
-        execute!(self.terminal.backend_mut(), LeaveAlternateScreen)?;
-        Ok(())
-    }
+    // Choix numérotés, le choix courant est surligné
+    let items: Vec<ListItem> = view
+        .choices
+        .iter()
+        .enumerate()
+        .map(|(index, choice)| ListItem::new(format!("{}. {}", index + 1, choice.label)))
+        .collect();
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ");
+    let mut state = ListState::default().with_selected(Some(selected));
+    f.render_stateful_widget(list, layout[2], &mut state);
+
+    let help = if view.input.is_some() {
+        "Tapez votre texte - Haut/Bas choisir - Entree valider - Echap retour"
+    } else {
+        "Haut/Bas ou numero choisir - Entree valider - Echap retour"
+    };
+    f.render_widget(Paragraph::new(help).alignment(Alignment::Center), layout[3]);
 }
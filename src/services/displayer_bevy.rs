@@ -1,23 +1,28 @@
-use crate::models::aptitude::Aptitude;
+use crate::services::cli::CliArgs;
+use crate::services::content::GameContent;
+use crate::services::json_loader::{JsonLoader, LoadError};
+use crate::services::replay::{Replay, ReplayHeader, ReplayPlayer, ReplayRecorder};
 use bevy::prelude::*;
 use std::io;
 
 // Import des constantes et etats
 use crate::services::ui::constants::{
-    AppState, ButtonAction, GameLoadContext, GREEN, NORMAL_BUTTON, RED, WHITE,
+    AppState, ButtonAction, ChangedButton, GameLoadContext, GREEN, NORMAL_BUTTON, RED, WHITE,
 };
 // Import des ressources et composants depuis les sous-modules
 use crate::services::ui::aptitudes_screen::{
-    despawn_aptitudes_screen, setup_aptitudes_screen, AptitudeList,
+    despawn_aptitudes_screen, setup_aptitudes_screen,
 };
 use crate::services::ui::game::{
-    despawn_game, handle_game_button_actions, setup_game, GameScreenState,
+    despawn_game, handle_game_button_actions, handle_typed_answer_input, load_current_player,
+    save_current_player, save_current_player_on_exit, setup_game, CurrentPlayer, GameScreenState,
+    GameSeed, SaveDirectory, StartArena,
 };
+use crate::services::ui::load_error_screen::{LoadErrorScreenPlugin, LoadErrors};
 use crate::services::ui::main_menu::{despawn_main_menu, setup_main_menu};
-use crate::services::ui::player_creation_screen::{
-    create_player, PlayerCreationData, PlayerCreationPlugin,
-};
+use crate::services::ui::player_creation_screen::PlayerCreationPlugin;
 use crate::services::ui::player_slot_screen::{PlayerSlotScreenPlugin, SelectedPlayerSlot};
+use crate::services::ui::replay::{BevyInput, ReplayPlayback, ReplayPlugin, ReplayRecording};
 use crate::services::ui::start_screen::StartScreenPlugin;
 
 /// Plugin principal de l'interface utilisateur avec Bevy
@@ -29,6 +34,10 @@ impl Plugin for DisplayerBevy {
         app.init_state::<AppState>()
             .init_resource::<GameLoadContext>()
             .init_resource::<GameScreenState>()
+            .init_resource::<CurrentPlayer>()
+            .init_resource::<SaveDirectory>()
+            .init_resource::<StartArena>()
+            .init_resource::<GameSeed>()
             .add_systems(Startup, setup)
             .add_systems(Update, (button_system, handle_game_button_actions))
             // Menu principal
@@ -38,53 +47,104 @@ impl Plugin for DisplayerBevy {
             .add_systems(OnEnter(AppState::Aptitudes), setup_aptitudes_screen)
             .add_systems(OnExit(AppState::Aptitudes), despawn_aptitudes_screen)
             // Ecran de jeu
-            .add_systems(OnEnter(AppState::Game), setup_game)
-            .add_systems(OnExit(AppState::Game), despawn_game)
+            .add_systems(OnEnter(AppState::Game), (load_current_player, setup_game).chain())
+            .add_systems(OnExit(AppState::Game), (save_current_player, despawn_game))
+            .add_systems(
+                Update,
+                handle_typed_answer_input.run_if(in_state(AppState::Game)),
+            )
+            .add_systems(Last, save_current_player_on_exit.run_if(in_state(AppState::Game)))
             // plugin pour les slots de joueur
             .add_plugins(PlayerSlotScreenPlugin)
             // plugin pour la creation de personnage
             .add_plugins(PlayerCreationPlugin)
             // plugin pour l'ecran de demarrage
-            .add_plugins(StartScreenPlugin);
+            .add_plugins(StartScreenPlugin)
+            // plugin pour l'ecran d'erreur de chargement
+            .add_plugins(LoadErrorScreenPlugin)
+            // plugin pour l'enregistrement et le rejeu des sessions
+            .add_plugins(ReplayPlugin);
     }
 }
 
 impl DisplayerBevy {
-    // Onc Crée une nouvelle instance du plugin d'affichage
+    // On crée une nouvelle instance du plugin d'affichage
     pub fn new() -> Self {
         DisplayerBevy
     }
 
-    // On lance l'application Bevy avec les aptitudes données
-    pub fn run(&self, aptitudes: &[Aptitude]) -> io::Result<()> {
-        App::new()
-            .add_plugins(DefaultPlugins)
-            .insert_resource(AptitudeList {
-                aptitudes: aptitudes.to_vec(),
+    // On lance l'application Bevy avec le contenu du jeu
+    // et les erreurs de chargement à afficher au démarrage
+    // Les options de la ligne de commande choisissent les dossiers, le slot et l'arène de départ
+    // Une session enregistrée repart de la graine, des slots et des options de l'enregistrement
+    pub fn run(
+        &self,
+        content: GameContent,
+        load_errors: Vec<LoadError>,
+        args: &CliArgs,
+        replay: Option<Replay<BevyInput>>,
+    ) -> io::Result<()> {
+        let mut app = App::new();
+        app.add_plugins(DefaultPlugins.set(AssetPlugin {
+            file_path: args.asset_dir.clone(),
+            ..Default::default()
+        }));
+
+        // Pendant un rejeu, les slots enregistrés sont recréés dans un dossier temporaire
+        let player = replay.map(ReplayPlayer::new).transpose()?;
+        let (save_dir, mut seed, slot, arena) = match &player {
+            Some(player) => {
+                let header = &player.replay.header;
+                (player.save_dir(), Some(header.seed), header.slot, header.arena.clone())
+            }
+            None => (
+                SaveDirectory(args.save_dir.clone()),
+                args.seed,
+                args.slot,
+                args.arena.clone(),
+            ),
+        };
+
+        // Enregistrement de la session, avec une graine fixée pour pouvoir la rejouer
+        let recorder = match (&args.record, &player) {
+            (Some(path), None) => {
+                let header =
+                    ReplayHeader::capture::<BevyInput>(&save_dir, seed, slot, arena.clone());
+                seed = Some(header.seed);
+                ReplayRecorder::create(path.clone(), &header)
+                    .map_err(|e| eprintln!("Impossible d'enregistrer le rejeu: {}", e))
+                    .ok()
+            }
+            _ => None,
+        };
+
+        // Avec un slot ou une arène de départ, on entre directement en jeu
+        if slot.is_some() || arena.is_some() {
+            app.insert_state(AppState::Game);
+        }
+
+        app.insert_resource(content)
+            .insert_resource(LoadErrors {
+                errors: load_errors,
             })
-            .init_resource::<SelectedPlayerSlot>()
-            .init_resource::<PlayerCreationData>()
-            .init_resource::<GameLoadContext>()
-            .init_resource::<GameScreenState>()
+            .insert_resource(save_dir)
+            .insert_resource(SelectedPlayerSlot { slot })
+            .insert_resource(StartArena(arena))
+            .insert_resource(GameSeed(seed))
+            .insert_resource(ReplayRecording(recorder))
+            .insert_resource(ReplayPlayback(player))
+            // Les autres ressources sont initialisées par le plugin et ses sous-plugins
             .add_plugins(DisplayerBevy::new())
             .run();
 
         Ok(())
     }
 }
-// Ressource pour la police UI principale
-#[derive(Resource, Clone)]
-pub struct UIFont(pub Handle<Font>);
-
-// Configuration initiale de la caméra et des ressources UI
-fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+// Configuration initiale de la caméra
+// Les textes utilisent la police par défaut de Bevy
+fn setup(mut commands: Commands) {
     // Caméra 2D principale
-    commands.spawn(Camera2d::default());
-    // Chargement de la police UI
-
-    let font_handle = asset_server.load("fonts/NotoSans-Regular.ttf");
-
-    commands.insert_resource(UIFont(font_handle));
+    commands.spawn(Camera2d);
 }
 
 // Système de gestion des interactions avec les boutons
@@ -96,11 +156,13 @@ fn button_system(
             &mut BackgroundColor,
             &mut BorderColor,
         ),
-        (Changed<Interaction>, With<Button>),
+        ChangedButton,
     >,
     mut app_state: ResMut<NextState<AppState>>,
     mut selected_slot: ResMut<SelectedPlayerSlot>,
     mut game_load_context: ResMut<GameLoadContext>,
+    save_dir: Res<SaveDirectory>,
+    mut exit: EventWriter<AppExit>,
 ) {
     for (interaction, action, mut background_color, mut border_color) in
         interaction_query.iter_mut()
@@ -124,8 +186,9 @@ fn button_system(
                     ButtonAction::ShowAptitudes => {
                         app_state.set(AppState::Aptitudes);
                     }
+                    // Fermeture normale de l'application, comme avec la croix de la fenêtre
                     ButtonAction::Quit => {
-                        std::process::exit(0);
+                        exit.send(AppExit::Success);
                     }
                     ButtonAction::Back => {
                         app_state.set(AppState::MainMenu);
@@ -133,6 +196,18 @@ fn button_system(
                     ButtonAction::SelectSlot(slot_index) => {
                         selected_slot.slot = Some(*slot_index);
                     }
+                    ButtonAction::RestoreSlot(slot_index) => {
+                        let file_path = save_dir.slot_path(*slot_index);
+                        match JsonLoader::restore_latest_backup(&file_path) {
+                            Ok(save) => println!(
+                                "Sauvegarde de {} restauree dans {}",
+                                save.player.caracter.name, file_path
+                            ),
+                            Err(e) => println!("Erreur lors de la restauration du slot: {}", e),
+                        }
+                        // On recharge l'ecran des slots pour afficher le resultat
+                        app_state.set(AppState::PlayerSlot);
+                    }
                     ButtonAction::ConfirmSlot => {
                         if selected_slot.slot.is_some() {
                             if game_load_context.is_load_game {
@@ -142,10 +217,8 @@ fn button_system(
                             }
                         }
                     }
-                    ButtonAction::CreatePlayer => {
-                        let _player = create_player(&PlayerCreationData::default());
-                        app_state.set(AppState::Game);
-                    }
+                    // La création est validée et sauvegardée par `PlayerCreationPlugin`
+                    ButtonAction::CreatePlayer => {}
                     ButtonAction::StartGame => {
                         app_state.set(AppState::Game);
                     }
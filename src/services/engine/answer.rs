@@ -0,0 +1,138 @@
+/// Articles ignorés au début d'une réponse tapée (« l'homme » vaut « homme »)
+const LEADING_ARTICLES: [&str; 10] = ["le", "la", "les", "l", "un", "une", "des", "du", "de", "d"];
+
+/// Normalise une réponse tapée pour la comparer aux réponses acceptées :
+/// minuscules, accents retirés, ponctuation remplacée par des espaces
+/// et articles du début supprimés.
+///
+/// # Exemple
+/// ```
+/// assert_eq!(normalize_answer("  L'Être humain !"), "etre humain");
+/// ```
+pub fn normalize_answer(input: &str) -> String {
+    let folded: String = input
+        .to_lowercase()
+        .chars()
+        .map(|c| match fold_accent(c) {
+            Some(folded) => folded.to_string(),
+            None if c.is_alphanumeric() => c.to_string(),
+            None => " ".to_string(),
+        })
+        .collect();
+
+    let mut words: Vec<&str> = folded.split_whitespace().collect();
+    // On garde toujours le dernier mot, même si c'est un article
+    while words.len() > 1 && LEADING_ARTICLES.contains(&words[0]) {
+        words.remove(0);
+    }
+    words.join(" ")
+}
+
+/// Distance d'édition (Levenshtein) entre deux chaînes, comptée en caractères
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Nombre de fautes de frappe tolérées selon la longueur de la réponse attendue
+fn allowed_typos(expected_len: usize) -> usize {
+    match expected_len {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+/// Vrai si la réponse tapée correspond à une des réponses acceptées,
+/// après normalisation et avec une petite tolérance aux fautes de frappe.
+///
+/// # Arguments
+/// * `input` - La réponse tapée par le joueur.
+/// * `accepted` - Les réponses acceptées pour l'énigme.
+pub fn is_answer_accepted(input: &str, accepted: &[String]) -> bool {
+    let input = normalize_answer(input);
+    if input.is_empty() {
+        return false;
+    }
+
+    accepted.iter().any(|answer| {
+        let expected = normalize_answer(answer);
+        edit_distance(&input, &expected) <= allowed_typos(expected.chars().count())
+    })
+}
+
+// Retire l'accent d'une lettre minuscule
+fn fold_accent(c: char) -> Option<&'static str> {
+    Some(match c {
+        'à' | 'â' | 'ä' | 'á' => "a",
+        'é' | 'è' | 'ê' | 'ë' => "e",
+        'î' | 'ï' | 'í' => "i",
+        'ô' | 'ö' | 'ó' => "o",
+        'ù' | 'û' | 'ü' | 'ú' => "u",
+        'ÿ' => "y",
+        'ç' => "c",
+        'ñ' => "n",
+        'œ' => "oe",
+        'æ' => "ae",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(list: &[&str]) -> Vec<String> {
+        list.iter().map(|answer| answer.to_string()).collect()
+    }
+
+    /// Test de la normalisation des réponses
+    /// La casse, les accents, la ponctuation et les articles du début sont ignorés.
+    #[test]
+    fn test_normalize_answer() {
+        assert_eq!(normalize_answer("L'homme"), "homme");
+        assert_eq!(normalize_answer("  L'Être HUMAIN !"), "etre humain");
+        assert_eq!(normalize_answer("de la bière"), "biere");
+        assert_eq!(normalize_answer("Cœur"), "coeur");
+        assert_eq!(normalize_answer("Le"), "le");
+        assert_eq!(normalize_answer("?!"), "");
+    }
+
+    /// Test de la distance d'édition
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("pastis", "pastis"), 0);
+        assert_eq!(edit_distance("pastis", "pastiss"), 1);
+        assert_eq!(edit_distance("piano", "pinao"), 2);
+        assert_eq!(edit_distance("", "trou"), 4);
+        assert_eq!(edit_distance("biere", "bière"), 1);
+    }
+
+    /// Test de la comparaison tolérante aux fautes de frappe
+    /// Une faute est tolérée sur un mot moyen, aucune sur un mot très court.
+    #[test]
+    fn test_is_answer_accepted() {
+        let accepted = answers(&["L'homme", "L'être humain"]);
+
+        assert!(is_answer_accepted("homme", &accepted));
+        assert!(is_answer_accepted("un home", &accepted));
+        assert!(is_answer_accepted("etre humian", &accepted));
+        assert!(!is_answer_accepted("femme", &accepted));
+        assert!(!is_answer_accepted("le temps", &accepted));
+        assert!(!is_answer_accepted("", &accepted));
+
+        let short = answers(&["Eau"]);
+        assert!(is_answer_accepted("l'eau", &short));
+        assert!(!is_answer_accepted("oui", &short));
+    }
+}
@@ -0,0 +1,369 @@
+use crate::models::arena::Arena;
+use crate::models::caracter::bouncer::AnswerMode;
+use crate::services::engine::answer::is_answer_accepted;
+use crate::services::engine::event::GameEvent;
+use crate::services::engine::game_engine::GameEngine;
+use crate::services::engine::state::GameScreenType;
+
+// Sélection des arènes, déblocage et question du videur à l'entrée
+impl GameEngine {
+    /// Vrai si toutes les arènes requises pour entrer dans cette arène ont été gagnées
+    /// et si le joueur possède tous les badges qu'elle demande
+    pub fn is_arena_unlocked(&self, arena: &Arena) -> bool {
+        arena
+            .unlock_requirements
+            .iter()
+            .all(|required| self.beaten_arenas.contains(required))
+            && self.missing_badges(arena).is_empty()
+    }
+
+    /// Badges demandés pour entrer dans une arène.
+    /// L'arène du championnat demande les badges de toutes les autres arènes.
+    pub fn required_badges(&self, arena: &Arena) -> Vec<String> {
+        if !arena.championship {
+            return arena.required_badges.clone();
+        }
+        self.badge_collection()
+            .into_iter()
+            .filter(|(other, _)| !other.championship)
+            .map(|(_, badge)| badge.name.clone())
+            .collect()
+    }
+
+    /// Badges demandés par une arène que le joueur ne possède pas encore
+    pub fn missing_badges(&self, arena: &Arena) -> Vec<String> {
+        self.required_badges(arena)
+            .into_iter()
+            .filter(|badge| !self.has_badge(badge))
+            .collect()
+    }
+
+    /// Vrai si l'arène doit apparaître dans la sélection
+    /// Une arène cachée n'apparaît qu'une fois déverrouillée.
+    pub fn is_arena_visible(&self, arena: &Arena) -> bool {
+        !arena.hidden || self.is_arena_unlocked(arena)
+    }
+
+    // Mémorise l'arène choisie et passe à la question de son videur
+    pub(super) fn choose_arena(&mut self, arena_index: usize, events: &mut Vec<GameEvent>) {
+        let Some(arena) = self.arenas.get(arena_index) else {
+            return;
+        };
+        if !self.is_arena_visible(arena) {
+            return;
+        }
+        if !self.is_arena_unlocked(arena) {
+            let mut missing: Vec<String> = arena
+                .unlock_requirements
+                .iter()
+                .filter(|required| !self.beaten_arenas.contains(required))
+                .cloned()
+                .collect();
+            missing.extend(self.missing_badges(arena));
+            self.locked_arena_message = Some(format!(
+                "{} est verrouillee : gagnez d'abord {}",
+                arena.name,
+                missing.join(", ")
+            ));
+            events.push(GameEvent::ArenaLocked(arena.name.clone()));
+            return;
+        }
+
+        self.wrong_answer_message = false;
+        self.locked_arena_message = None;
+        self.selected_arena = Some(arena.name.clone());
+        self.selected_arena_index = Some(arena_index);
+        events.push(GameEvent::ArenaChosen(arena.name.clone()));
+
+        self.load_bouncer_question();
+        self.change_screen(GameScreenType::BouncerQuestion, events);
+    }
+
+    // Tire au hasard une énigme du videur de l'arène sélectionnée
+    // et mélange l'ordre des réponses proposées
+    fn load_bouncer_question(&mut self) {
+        let bouncer = self.selected_arena_data().and_then(|arena| {
+            self.bouncers
+                .iter()
+                .find(|bouncer| bouncer.id == arena.bouncer_id)
+                .cloned()
+        });
+        let enigma = bouncer
+            .as_ref()
+            .and_then(|bouncer| self.rng.choose(&bouncer.enigmas))
+            .cloned();
+
+        self.bouncer_name = bouncer
+            .as_ref()
+            .map(|bouncer| bouncer.pnj.caracter.name.clone());
+        self.answer_mode = bouncer.map_or(AnswerMode::Choices, |bouncer| bouncer.answer_mode);
+        self.hint_revealed = false;
+        self.typed_answer.clear();
+        match enigma {
+            Some(enigma) => {
+                self.current_question = enigma.question.clone();
+                self.correct_answer = enigma.answer.clone();
+                self.accepted_answers = enigma.all_accepted_answers();
+                self.current_hint = enigma.hint.clone();
+                self.answer_options = enigma.options();
+                self.rng.shuffle(&mut self.answer_options);
+            }
+            None => {
+                self.current_question = "Question par defaut".to_string();
+                self.correct_answer = String::new();
+                self.accepted_answers.clear();
+                self.current_hint = None;
+                self.answer_options.clear();
+            }
+        }
+    }
+
+    // Révèle l'indice de l'énigme, une seule fois
+    pub(super) fn show_hint(&mut self, events: &mut Vec<GameEvent>) {
+        if let (Some(hint), false) = (&self.current_hint, self.hint_revealed) {
+            self.hint_revealed = true;
+            events.push(GameEvent::HintRevealed(hint.clone()));
+        }
+    }
+
+    // Répond au videur avec une des réponses proposées
+    pub(super) fn answer_choice(&mut self, answer_index: usize, events: &mut Vec<GameEvent>) {
+        if self.answer_mode != AnswerMode::Choices {
+            return;
+        }
+        if let Some(selected_answer) = self.answer_options.get(answer_index) {
+            let correct = *selected_answer == self.correct_answer;
+            self.answer_bouncer(correct, events);
+        }
+    }
+
+    // Met à jour la réponse tapée pour un videur qui demande une réponse libre
+    pub(super) fn type_answer(&mut self, text: String, events: &mut Vec<GameEvent>) {
+        if self.answer_mode == AnswerMode::FreeText
+            && self.current_screen == GameScreenType::BouncerQuestion
+            && text != self.typed_answer
+        {
+            self.typed_answer = text.clone();
+            events.push(GameEvent::AnswerTyped(text));
+        }
+    }
+
+    // Compare la réponse tapée aux réponses acceptées par le videur
+    pub(super) fn submit_typed_answer(&mut self, events: &mut Vec<GameEvent>) {
+        if self.answer_mode == AnswerMode::FreeText && !self.typed_answer.trim().is_empty() {
+            let correct = is_answer_accepted(&self.typed_answer, &self.accepted_answers);
+            self.answer_bouncer(correct, events);
+        }
+    }
+
+    // Laisse entrer le joueur dans l'arène ou le renvoie à la sélection selon sa réponse au videur
+    fn answer_bouncer(&mut self, correct: bool, events: &mut Vec<GameEvent>) {
+        events.push(GameEvent::BouncerAnswered { correct });
+
+        if correct {
+            self.load_master_data(events);
+            self.change_screen(GameScreenType::ArenaPresentation, events);
+        } else {
+            self.wrong_answer_message = true;
+            self.change_screen(GameScreenType::ArenaSelection, events);
+        }
+    }
+
+    // Charge les données du maître de l'arène sélectionnée
+    pub(super) fn load_master_data(&mut self, events: &mut Vec<GameEvent>) {
+        let Some(master) = self.selected_master().cloned() else {
+            return;
+        };
+
+        events.push(GameEvent::MasterLoaded(master.pnj.caracter.name.clone()));
+        self.master_name = Some(master.pnj.caracter.name);
+        self.master_style = Some(master.pnj.caracter.style);
+        self.master_badge = Some(master.badge.name);
+        self.master_attacks = master.attacks;
+        self.master_dialogs = master.pnj.dialogs;
+        self.master_recipe = Some(master.recipe);
+        self.boss_max_hp = master.pnj.caracter.hp;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::caracter::bouncer::AnswerMode;
+    use crate::services::engine::command::GameCommand;
+    use crate::services::engine::event::GameEvent;
+    use crate::services::engine::state::GameScreenType;
+    use crate::services::engine::test_support::*;
+
+    /// Test d'une mauvaise réponse au videur
+    /// Le joueur doit être renvoyé à la sélection d'arène avec le message d'erreur.
+    #[test]
+    fn test_wrong_bouncer_answer_returns_to_selection() {
+        let mut engine = test_engine();
+        engine.apply(GameCommand::ChooseArena(0));
+        let wrong_index = engine
+            .answer_options
+            .iter()
+            .position(|option| *option != engine.correct_answer)
+            .unwrap();
+
+        let events = engine.apply(GameCommand::AnswerBouncer(wrong_index));
+
+        assert!(events.contains(&GameEvent::BouncerAnswered { correct: false }));
+        assert_eq!(engine.current_screen, GameScreenType::ArenaSelection);
+        assert!(engine.wrong_answer_message);
+        assert!(engine.master_name.is_none());
+    }
+
+    /// Test de la résolution du maître par id
+    /// Les arènes sont triées par difficulté et chacune retrouve son maître
+    /// quel que soit l'ordre des fichiers.
+    #[test]
+    fn test_master_is_resolved_by_id() {
+        let mut engine = test_engine();
+
+        assert_eq!(engine.arenas[0].id, "pastis");
+        enter_combat(&mut engine);
+
+        assert_eq!(engine.master_name.as_deref(), Some("Pastis Man"));
+        assert_eq!(engine.current_question, "Qui suis-je ?");
+        assert_eq!(engine.bouncer_name.as_deref(), Some("Ragnar"));
+    }
+
+    /// Test de l'énigme tirée dans les données du videur
+    /// Les réponses proposées sont la bonne réponse et ses distracteurs, et l'indice
+    /// n'est révélé qu'une fois à la demande.
+    #[test]
+    fn test_bouncer_enigma_comes_from_data() {
+        let mut engine = test_engine();
+        engine.apply(GameCommand::ChooseArena(0));
+
+        let mut options = engine.answer_options.clone();
+        options.sort();
+        assert_eq!(options, vec!["L'homme", "Le temps", "Un animal"]);
+        assert_eq!(engine.correct_answer, "L'homme");
+        assert!(!engine.hint_revealed);
+
+        let events = engine.apply(GameCommand::ShowHint);
+        assert_eq!(
+            events,
+            vec![GameEvent::HintRevealed("Il marche avec une canne le soir".to_string())]
+        );
+        assert!(engine.apply(GameCommand::ShowHint).is_empty());
+
+        let events = answer_bouncer_correctly(&mut engine);
+        assert!(events.contains(&GameEvent::BouncerAnswered { correct: true }));
+    }
+
+    /// Test d'une arène verrouillée
+    /// Elle ne peut être choisie qu'après avoir gagné les arènes requises.
+    #[test]
+    fn test_locked_arena_requires_beaten_arenas() {
+        let mut engine = test_engine();
+        engine.apply(GameCommand::OpenArenaSelection);
+
+        let events = engine.apply(GameCommand::ChooseArena(1));
+        assert_eq!(events, vec![GameEvent::ArenaLocked("CIDRE".to_string())]);
+        assert!(engine.locked_arena_message.is_some());
+        assert_eq!(engine.current_screen, GameScreenType::ArenaSelection);
+
+        win_arena(&mut engine);
+        assert_eq!(engine.beaten_arenas, vec!["pastis"]);
+
+        let events = engine.apply(GameCommand::ChooseArena(1));
+        assert!(events.contains(&GameEvent::ArenaChosen("CIDRE".to_string())));
+        answer_bouncer_correctly(&mut engine);
+        assert_eq!(engine.master_name.as_deref(), Some("Cidre Lord"));
+        assert_eq!(engine.boss_max_hp, 200);
+    }
+
+    /// Test d'une arène cachée
+    /// Elle n'apparaît et ne peut être choisie qu'une fois déverrouillée.
+    #[test]
+    fn test_hidden_arena_is_invisible_until_unlocked() {
+        let mut engine = test_engine();
+        engine.arenas[1].hidden = true;
+
+        assert!(!engine.is_arena_visible(&engine.arenas[1]));
+        assert!(engine.apply(GameCommand::ChooseArena(1)).is_empty());
+
+        engine.beaten_arenas.push("pastis".to_string());
+        assert!(engine.is_arena_visible(&engine.arenas[1]));
+    }
+
+    /// Test d'une arène gardée par un badge
+    /// Le joueur doit posséder le badge demandé, que la victoire sur le maître lui donne.
+    #[test]
+    fn test_arena_requires_held_badges() {
+        let mut engine = test_engine();
+        engine.arenas[1].unlock_requirements.clear();
+        engine.arenas[1].required_badges = vec!["Pastis Badge".to_string()];
+        engine.set_player(test_player());
+
+        let events = engine.apply(GameCommand::ChooseArena(1));
+        assert_eq!(events, vec![GameEvent::ArenaLocked("CIDRE".to_string())]);
+        assert!(engine.locked_arena_message.as_ref().unwrap().contains("Pastis Badge"));
+
+        win_arena(&mut engine);
+        assert!(engine.has_badge("Pastis Badge"));
+        let events = engine.apply(GameCommand::ChooseArena(1));
+        assert!(events.contains(&GameEvent::ArenaChosen("CIDRE".to_string())));
+    }
+
+    /// Test de l'arène du championnat
+    /// Elle demande les badges de toutes les autres arènes ; sans joueur chargé,
+    /// les badges des arènes gagnées comptent.
+    #[test]
+    fn test_championship_requires_every_badge() {
+        let mut engine = test_engine();
+        let mut championship = arena("final", "pastis_man", 4, &[]);
+        championship.championship = true;
+        engine.arenas.push(championship.clone());
+
+        assert_eq!(engine.required_badges(&championship), vec!["Pastis Badge", "Cidre Badge"]);
+        engine.beaten_arenas.push("pastis".to_string());
+        assert_eq!(engine.missing_badges(&championship), vec!["Cidre Badge"]);
+        assert!(!engine.is_arena_unlocked(&championship));
+
+        engine.beaten_arenas.push("cidre".to_string());
+        assert!(engine.is_arena_unlocked(&championship));
+    }
+
+    /// Test d'un videur qui demande une réponse tapée
+    /// La saisie est comparée sans tenir compte des accents, des articles
+    /// ni d'une petite faute de frappe, et les boutons de choix sont ignorés.
+    #[test]
+    fn test_free_text_bouncer_accepts_close_answers() {
+        let mut engine = test_engine();
+        engine.bouncers[0].answer_mode = AnswerMode::FreeText;
+        engine.apply(GameCommand::OpenArenaSelection);
+        engine.apply(GameCommand::ChooseArena(0));
+
+        assert_eq!(engine.answer_mode, AnswerMode::FreeText);
+        assert!(engine.apply(GameCommand::AnswerBouncer(0)).is_empty());
+        assert!(engine.apply(GameCommand::SubmitTypedAnswer).is_empty());
+
+        let events = engine.apply(GameCommand::TypeAnswer("un etre humian".to_string()));
+        assert_eq!(events, vec![GameEvent::AnswerTyped("un etre humian".to_string())]);
+        let events = engine.apply(GameCommand::SubmitTypedAnswer);
+
+        assert!(events.contains(&GameEvent::BouncerAnswered { correct: true }));
+        assert_eq!(engine.current_screen, GameScreenType::ArenaPresentation);
+    }
+
+    /// Test d'une mauvaise réponse tapée
+    /// Le joueur est renvoyé à la sélection et sa saisie est effacée à la prochaine énigme.
+    #[test]
+    fn test_free_text_bouncer_rejects_wrong_answer() {
+        let mut engine = test_engine();
+        engine.bouncers[0].answer_mode = AnswerMode::FreeText;
+        engine.apply(GameCommand::ChooseArena(0));
+
+        engine.apply(GameCommand::TypeAnswer("Le temps".to_string()));
+        let events = engine.apply(GameCommand::SubmitTypedAnswer);
+
+        assert!(events.contains(&GameEvent::BouncerAnswered { correct: false }));
+        assert!(engine.wrong_answer_message);
+        engine.apply(GameCommand::ChooseArena(0));
+        assert!(engine.typed_answer.is_empty());
+    }
+}
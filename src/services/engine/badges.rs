@@ -0,0 +1,134 @@
+use crate::models::arena::Arena;
+use crate::models::badge::{Badge, BadgeEffect};
+use crate::services::engine::event::GameEvent;
+use crate::services::engine::game_engine::GameEngine;
+
+// Badges du joueur et leurs effets passifs
+impl GameEngine {
+    /// Vrai si le joueur possède un badge.
+    /// Sans joueur chargé, les badges des arènes déjà gagnées comptent comme possédés.
+    pub fn has_badge(&self, badge_name: &str) -> bool {
+        match &self.player {
+            Some(player) => player.badges_won.iter().any(|badge| badge.name == badge_name),
+            None => self.badge_collection().into_iter().any(|(arena, badge)| {
+                badge.name == badge_name && self.beaten_arenas.contains(&arena.id)
+            }),
+        }
+    }
+
+    /// Tous les badges de la campagne, dans l'ordre des arènes, avec l'arène qui les donne
+    pub fn badge_collection(&self) -> Vec<(&Arena, &Badge)> {
+        self.arenas
+            .iter()
+            .filter_map(|arena| {
+                self.masters
+                    .iter()
+                    .find(|master| master.id == arena.master_id)
+                    .map(|master| (arena, &master.badge))
+            })
+            .collect()
+    }
+
+    /// Effets passifs des badges du joueur : son badge de départ et les badges gagnés.
+    /// Sans joueur chargé, aucun effet ne s'applique.
+    pub fn badge_effects(&self) -> Vec<&BadgeEffect> {
+        self.player
+            .iter()
+            .flat_map(|player| std::iter::once(&player.badge).chain(&player.badges_won))
+            .flat_map(|badge| &badge.effects)
+            .collect()
+    }
+
+    /// Multiplicateur de dégâts donné par les badges dans l'arène sélectionnée
+    pub fn badge_damage_factor(&self) -> f32 {
+        let theme = self.selected_arena_data().map(|arena| arena.theme.as_str());
+        let percent: u32 = self
+            .badge_effects()
+            .into_iter()
+            .filter_map(|effect| match effect {
+                BadgeEffect::DamageBonus { theme: bonus_theme, percent } => bonus_theme
+                    .as_deref()
+                    .is_none_or(|bonus_theme| {
+                        theme.is_some_and(|theme| theme.eq_ignore_ascii_case(bonus_theme))
+                    })
+                    .then_some(*percent),
+                _ => None,
+            })
+            .sum();
+        1.0 + percent as f32 / 100.0
+    }
+
+    // Révèle un ingrédient de la recette pour chaque badge d'indice du joueur
+    pub(super) fn apply_badge_hints(&mut self, events: &mut Vec<GameEvent>) {
+        let hints = self
+            .badge_effects()
+            .into_iter()
+            .filter(|effect| **effect == BadgeEffect::IngredientHint)
+            .count();
+        if hints == 0 {
+            return;
+        }
+
+        let revealed = self.reveal_ingredients(hints, events);
+        if !revealed.is_empty() {
+            self.aptitude_message = Some(format!("Vos badges revelent : {}", revealed.join(", ")));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::badge::{Badge, BadgeEffect};
+    use crate::models::caracter::player::PLAYER_START_BANKROLL;
+    use crate::services::engine::command::GameCommand;
+    use crate::services::engine::test_support::*;
+
+    /// Test des effets passifs des badges
+    /// Un badge gagné révèle un ingrédient au début du combat, augmente les dégâts
+    /// dans les arènes de son thème et baisse les prix des marchands.
+    #[test]
+    fn test_badge_effects_apply_in_combat_and_shop() {
+        let mut engine = test_engine();
+        engine.traders[0].ingredients[0].price = 10;
+        let mut player = test_player();
+        player.badges_won.push(Badge {
+            name: "Badge Test".to_string(),
+            features: vec![],
+            effects: vec![
+                BadgeEffect::IngredientHint,
+                BadgeEffect::DamageBonus { theme: Some("test".to_string()), percent: 50 },
+                BadgeEffect::TraderDiscount { percent: 50 },
+            ],
+        });
+        engine.set_player(player);
+
+        enter_combat(&mut engine);
+        assert_eq!(engine.revealed_ingredients, vec!["Pastis"]);
+        craft_correct_cocktail(&mut engine);
+        assert_eq!(engine.boss_hp, 30);
+
+        engine.apply(GameCommand::OpenShop);
+        engine.apply(GameCommand::ChooseTrader(0));
+        engine.apply(GameCommand::BuyIngredient(0));
+        assert_eq!(
+            engine.player.as_ref().unwrap().caracter.bankroll,
+            PLAYER_START_BANKROLL - 5
+        );
+    }
+
+    /// Test d'un bonus de dégâts réservé à un autre thème
+    #[test]
+    fn test_themed_damage_bonus_ignores_other_arenas() {
+        let mut engine = test_engine();
+        let mut player = test_player();
+        player.badge.effects = vec![BadgeEffect::DamageBonus {
+            theme: Some("Iles".to_string()),
+            percent: 50,
+        }];
+        engine.set_player(player);
+
+        enter_combat(&mut engine);
+
+        assert_eq!(engine.badge_damage_factor(), 1.0);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::services::engine::event::GameEvent;
+use crate::services::engine::game_engine::GameEngine;
+use crate::services::engine::state::{CurrentCocktailCrafting, GameScreenType};
+
+/// Réputation gagnée pour une commande de client bien servie
+const ORDER_SERVED_REPUTATION: u32 = 1;
+/// Expérience gagnée pour une commande de client bien servie, multipliée comme le pourboire
+const ORDER_SERVED_XP: u32 = 5;
+/// Gestes en plus du minimum (un par ingrédient et par instruction) encore considérés comme rapides
+const FAST_SERVICE_SPARE_GESTURES: u32 = 2;
+
+// Service au bar entre deux arènes : les clients commandent et laissent un pourboire
+impl GameEngine {
+    // Ouvre le bar et appelle un client s'il n'y en a pas déjà un au comptoir
+    pub(super) fn open_bar(&mut self, events: &mut Vec<GameEvent>) {
+        self.bar_message = None;
+        if self.current_client.is_none() {
+            self.call_next_client(events);
+        }
+        self.change_screen(GameScreenType::BarService, events);
+    }
+
+    // Fait entrer le client suivant au bar, chacun son tour
+    fn call_next_client(&mut self, events: &mut Vec<GameEvent>) {
+        self.current_crafting = CurrentCocktailCrafting::default();
+        self.bar_gestures = 0;
+        if self.clients.is_empty() {
+            self.current_client = None;
+            return;
+        }
+
+        let client = self.clients[self.next_client_index % self.clients.len()].clone();
+        self.next_client_index += 1;
+        self.shuffle_instructions(Some(client.order.instructions.clone()));
+        events.push(GameEvent::ClientArrived(client.pnj.caracter.name.clone()));
+        self.current_client = Some(client);
+    }
+
+    // Compte un geste de préparation quand le joueur sert au bar
+    pub(super) fn count_bar_gesture(&mut self, events: &[GameEvent]) {
+        if self.current_screen == GameScreenType::BarService && !events.is_empty() {
+            self.bar_gestures += 1;
+        }
+    }
+
+    // Sert la commande du client : les bons ingrédients sont indispensables, puis le pourboire
+    // est doublé si les instructions sont dans l'ordre, et augmenté encore si le service est rapide
+    pub(super) fn serve_order(&mut self, events: &mut Vec<GameEvent>) {
+        if self.current_screen != GameScreenType::BarService {
+            return;
+        }
+        let Some(client) = self.current_client.clone() else {
+            return;
+        };
+
+        let ingredients_correct = self.selection_matches(&client.order);
+        let order_correct = self.selected_instructions().into_iter().eq(&client.order.instructions);
+        let minimum_gestures = (client.order.ingredients.len() + client.order.instructions.len()) as u32;
+        let fast = self.bar_gestures <= minimum_gestures + FAST_SERVICE_SPARE_GESTURES;
+
+        self.consume_selection(Some(&client.order), ingredients_correct, events);
+
+        let name = client.pnj.caracter.name.clone();
+        let multiplier = if ingredients_correct {
+            1 + u32::from(order_correct) + u32::from(order_correct && fast)
+        } else {
+            0
+        };
+        let tip = client.tips * multiplier as i32;
+        if let Some(player) = self.player.as_mut() {
+            player.caracter.bankroll += tip;
+            player.reputation += ORDER_SERVED_REPUTATION * multiplier;
+        }
+        self.gain_xp(ORDER_SERVED_XP * multiplier, events);
+
+        let advice = self
+            .rng
+            .choose(&client.advices)
+            .map(|advice| format!("\n{} : \"{}\"", name, advice))
+            .unwrap_or_default();
+        self.bar_message = Some(if ingredients_correct {
+            format!("{} laisse {} pieces de pourboire.{}", name, tip, advice)
+        } else {
+            format!("{} repart sans payer : ce n'etait pas un {}.", name, client.drink)
+        });
+        events.push(GameEvent::OrderServed {
+            client: name,
+            correct: ingredients_correct,
+            tip,
+        });
+        self.call_next_client(events);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::caracter::player::PLAYER_START_BANKROLL;
+    use crate::services::engine::command::GameCommand;
+    use crate::services::engine::event::GameEvent;
+    use crate::services::engine::game_engine::GameEngine;
+    use crate::services::engine::state::GameScreenType;
+    use crate::services::engine::test_support::*;
+
+    /// Prépare la commande du client avec les bons ingrédients et les instructions dans l'ordre
+    fn prepare_order(engine: &mut GameEngine) {
+        engine.apply(GameCommand::ToggleIngredient("Pastis".to_string()));
+        engine.apply(GameCommand::ToggleIngredient("Eau".to_string()));
+        select_instruction(engine, "Verser le Pastis");
+        select_instruction(engine, "Ajouter l'eau");
+    }
+
+    /// Test d'une commande parfaite au bar
+    /// Bons ingrédients, bon ordre et service rapide triplent le pourboire du client,
+    /// et la commande consomme les quantités de sa recette.
+    #[test]
+    fn test_perfect_order_triples_tip() {
+        let mut engine = test_engine();
+        engine.set_player(test_player());
+
+        let events = engine.apply(GameCommand::OpenBar);
+        assert!(events.contains(&GameEvent::ClientArrived("Hugo".to_string())));
+        assert_eq!(engine.current_screen, GameScreenType::BarService);
+        prepare_order(&mut engine);
+        let events = engine.apply(GameCommand::ServeOrder);
+
+        assert!(events.contains(&GameEvent::OrderServed {
+            client: "Hugo".to_string(),
+            correct: true,
+            tip: 15,
+        }));
+        let player = engine.player.as_ref().unwrap();
+        assert_eq!(player.caracter.bankroll, PLAYER_START_BANKROLL + 15);
+        assert_eq!(player.reputation, 3);
+        assert_eq!(engine.inventory_count("Pastis"), Some(1));
+        assert_eq!(engine.inventory_count("Eau"), Some(0));
+        // Le client suivant arrive aussitôt avec une préparation vide
+        assert!(engine.current_client.is_some());
+        assert_eq!(engine.bar_gestures, 0);
+    }
+
+    /// Test d'une commande lente ou dans le désordre
+    /// Le service lent perd le bonus de rapidité, le mauvais ordre ne rapporte que le pourboire de base.
+    #[test]
+    fn test_slow_or_unordered_orders_earn_less() {
+        let mut engine = test_engine();
+        engine.set_player(test_player());
+        engine.apply(GameCommand::OpenBar);
+
+        for _ in 0..4 {
+            engine.apply(GameCommand::ToggleIngredient("Vodka".to_string()));
+        }
+        prepare_order(&mut engine);
+        let events = engine.apply(GameCommand::ServeOrder);
+        assert!(events.iter().any(|e| matches!(e, GameEvent::OrderServed { tip: 10, .. })));
+
+        engine.player.as_mut().unwrap().add_ingredient("Eau", 1);
+        engine.apply(GameCommand::ToggleIngredient("Pastis".to_string()));
+        engine.apply(GameCommand::ToggleIngredient("Eau".to_string()));
+        select_instruction(&mut engine, "Ajouter l'eau");
+        let events = engine.apply(GameCommand::ServeOrder);
+        assert!(events.iter().any(|e| matches!(e, GameEvent::OrderServed { tip: 5, .. })));
+    }
+
+    /// Test d'une mauvaise commande
+    /// Le client repart sans pourboire et les ingrédients versés sont perdus.
+    #[test]
+    fn test_wrong_order_earns_nothing() {
+        let mut engine = test_engine();
+        engine.set_player(test_player());
+        engine.apply(GameCommand::OpenBar);
+
+        engine.apply(GameCommand::ToggleIngredient("Vodka".to_string()));
+        let events = engine.apply(GameCommand::ServeOrder);
+
+        assert!(events.contains(&GameEvent::OrderServed {
+            client: "Hugo".to_string(),
+            correct: false,
+            tip: 0,
+        }));
+        assert_eq!(engine.player.as_ref().unwrap().caracter.bankroll, PLAYER_START_BANKROLL);
+        assert_eq!(engine.inventory_count("Vodka"), Some(0));
+    }
+}
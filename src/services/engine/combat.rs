@@ -0,0 +1,394 @@
+use crate::models::aptitude::AptitudeEffect;
+use crate::models::attack::StatusEffect;
+use crate::services::engine::event::GameEvent;
+use crate::services::engine::game_engine::GameEngine;
+use crate::services::engine::state::{ArenaCombatState, BossAttackReport, GameScreenType};
+
+// Combat au tour par tour contre le maître : aptitudes, attaques et fin de l'arène
+impl GameEngine {
+    // Quitte l'introduction de l'arène et donne la main au joueur
+    pub(super) fn start_arena_combat(&mut self, events: &mut Vec<GameEvent>) {
+        if self.current_screen != GameScreenType::Arena
+            || self.arena_combat_state != ArenaCombatState::Start
+        {
+            return;
+        }
+        self.show_intro_screen = false;
+        self.arena_combat_state = ArenaCombatState::PlayerTurn;
+        self.apply_badge_hints(events);
+        self.change_screen(GameScreenType::Arena, events);
+    }
+
+    // Utilise une aptitude du joueur : dépense ses PP, applique son effet puis le boss riposte
+    pub(super) fn use_aptitude(&mut self, aptitude_index: usize, events: &mut Vec<GameEvent>) {
+        if self.current_screen != GameScreenType::Arena
+            || self.arena_combat_state != ArenaCombatState::PlayerTurn
+        {
+            return;
+        }
+        let Some(aptitude) = self.player_aptitudes.get(aptitude_index).cloned() else {
+            return;
+        };
+        let Some(effect) = aptitude.effect.clone() else {
+            self.aptitude_message = Some(format!("{} n'a aucun effet en combat.", aptitude.name));
+            events.push(GameEvent::AptitudeRejected(aptitude.name));
+            return;
+        };
+        if self.player_pp < aptitude.pp {
+            self.aptitude_message = Some(format!("Pas assez de PP pour {}.", aptitude.name));
+            events.push(GameEvent::AptitudeRejected(aptitude.name));
+            return;
+        }
+
+        self.player_pp -= aptitude.pp;
+        events.push(GameEvent::AptitudeUsed {
+            name: aptitude.name.clone(),
+            pp_left: self.player_pp,
+        });
+
+        self.aptitude_message = Some(match effect {
+            AptitudeEffect::DamageBoost => {
+                self.pending_damage_boost = Some(aptitude.power);
+                format!("{} : prochains degats x{}", aptitude.name, aptitude.power)
+            }
+            AptitudeEffect::Shield => {
+                self.shield_active = true;
+                format!("{} : la prochaine attaque sera bloquee", aptitude.name)
+            }
+            AptitudeEffect::RevealIngredient => {
+                let count = (aptitude.power as usize).max(1);
+                let revealed = self.reveal_ingredients(count, events);
+                if revealed.is_empty() {
+                    format!("{} : aucun nouvel ingredient a reveler", aptitude.name)
+                } else {
+                    format!("{} : {}", aptitude.name, revealed.join(", "))
+                }
+            }
+        });
+
+        self.boss_turn(events);
+        if self.player_hp == 0 {
+            self.end_arena(events);
+        }
+    }
+
+    // Révèle des ingrédients corrects de la recette qui ne l'ont pas encore été
+    pub(super) fn reveal_ingredients(
+        &mut self,
+        count: usize,
+        events: &mut Vec<GameEvent>,
+    ) -> Vec<String> {
+        let Some(recipe) = &self.master_recipe else {
+            return vec![];
+        };
+
+        let revealed: Vec<String> = recipe
+            .ingredients
+            .iter()
+            .map(|item| item.ingredient.clone())
+            .filter(|name| !self.revealed_ingredients.contains(name))
+            .take(count)
+            .collect();
+
+        for name in &revealed {
+            self.revealed_ingredients.push(name.clone());
+            events.push(GameEvent::IngredientRevealed(name.clone()));
+        }
+        revealed
+    }
+
+    // Attaque du joueur sur le boss, réduite si le joueur est affaibli
+    // et augmentée par une aptitude de boost en attente et par les badges du joueur
+    pub(super) fn player_attack(&mut self, base_damage: i32, events: &mut Vec<GameEvent>) {
+        let boost = self.pending_damage_boost.take().unwrap_or(1.0) * self.badge_damage_factor();
+        let factor = boost * self
+            .player_status_effects
+            .iter()
+            .map(|effect| match effect {
+                StatusEffect::Weakness { factor, .. } => *factor,
+                StatusEffect::Poison { .. } => 1.0,
+            })
+            .product::<f32>();
+        self.damage_boss((base_damage as f32 * factor) as i32, events);
+    }
+
+    // Tour du boss : les effets de statut s'appliquent puis le maître choisit une attaque
+    pub(super) fn boss_turn(&mut self, events: &mut Vec<GameEvent>) {
+        self.arena_combat_state = ArenaCombatState::BossTurn;
+        self.apply_status_effects(events);
+
+        if self.player_hp > 0 {
+            if let Some(attack) = self.rng.choose(&self.master_attacks).cloned() {
+                let hit = self.rng.chance(attack.accuracy);
+                // Le bouclier absorbe entièrement une attaque qui touche
+                let blocked = hit && self.shield_active;
+                if blocked {
+                    self.shield_active = false;
+                }
+                let damage = if hit && !blocked { attack.damage } else { 0 };
+                events.push(GameEvent::BossAttacked {
+                    attack: attack.name.clone(),
+                    hit,
+                    damage,
+                });
+
+                if hit && !blocked {
+                    self.damage_player(damage, events);
+                    if let Some(effect) = &attack.status_effect {
+                        self.player_status_effects.push(effect.clone());
+                        events.push(GameEvent::StatusEffectApplied(effect.clone()));
+                    }
+                }
+
+                self.current_boss_attack = Some(BossAttackReport {
+                    attack: attack.name,
+                    hit,
+                    blocked,
+                    damage,
+                    status_effect: if hit && !blocked { attack.status_effect } else { None },
+                });
+            }
+        }
+
+        self.arena_combat_state = ArenaCombatState::PlayerTurn;
+    }
+
+    // Applique les effets de statut actifs et retire ceux qui sont terminés
+    fn apply_status_effects(&mut self, events: &mut Vec<GameEvent>) {
+        let poison_damage: i32 = self
+            .player_status_effects
+            .iter()
+            .map(|effect| match effect {
+                StatusEffect::Poison { damage, .. } => *damage,
+                StatusEffect::Weakness { .. } => 0,
+            })
+            .sum();
+
+        if poison_damage > 0 {
+            self.damage_player(poison_damage, events);
+        }
+
+        for effect in self.player_status_effects.iter_mut() {
+            effect.tick();
+        }
+        self.player_status_effects.retain(|effect| effect.turns() > 0);
+    }
+
+    // Inflige des dégâts au joueur sans descendre sous zéro
+    pub(super) fn damage_player(&mut self, amount: i32, events: &mut Vec<GameEvent>) {
+        self.player_hp = (self.player_hp - amount).max(0);
+        events.push(GameEvent::PlayerDamaged {
+            amount,
+            remaining: self.player_hp,
+        });
+    }
+
+    // Inflige des dégâts au boss sans descendre sous zéro
+    pub(super) fn damage_boss(&mut self, amount: i32, events: &mut Vec<GameEvent>) {
+        self.boss_hp = (self.boss_hp - amount).max(0);
+        events.push(GameEvent::BossDamaged {
+            amount,
+            remaining: self.boss_hp,
+        });
+    }
+
+    // Termine le combat et affiche l'écran de fin
+    pub(super) fn end_arena(&mut self, events: &mut Vec<GameEvent>) {
+        // Le joueur garde les HP et PP qui lui restent à la fin du combat.
+        // Battu, il est remis sur pied : sa sauvegarde ne garde jamais un joueur à 0 HP.
+        if let Some(player) = self.player.as_mut() {
+            player.caracter.hp = if self.player_hp == 0 { player.max_hp } else { self.player_hp };
+            player.caracter.pp = self.player_pp;
+        }
+        if self.player_hp == 0 {
+            self.arena_combat_state = ArenaCombatState::Defeat;
+            events.push(GameEvent::ArenaLost);
+        } else {
+            self.arena_combat_state = ArenaCombatState::Victory;
+            events.push(GameEvent::ArenaWon);
+            if let Some(arena) = self.selected_arena_data() {
+                if arena.championship {
+                    events.push(GameEvent::ChampionshipWon);
+                }
+                if !self.beaten_arenas.contains(&arena.id) {
+                    self.beaten_arenas.push(arena.id.clone());
+                }
+            }
+            self.reward_player(events);
+        }
+        self.change_screen(GameScreenType::ArenaEnd, events);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::aptitude::AptitudeEffect;
+    use crate::models::attack::StatusEffect;
+    use crate::models::save_game::SaveGame;
+    use crate::services::engine::command::GameCommand;
+    use crate::services::engine::event::GameEvent;
+    use crate::services::engine::test_support::*;
+    use crate::services::json_loader::JsonLoader;
+    use crate::services::session::{CurrentPlayer, SaveDirectory};
+    use tempfile::tempdir;
+
+    /// Test de la sauvegarde après une arène perdue
+    /// Le joueur battu est sauvegardé avec ses HP maximum, et non à 0 HP.
+    #[test]
+    fn test_lost_arena_saves_player_at_max_hp() {
+        let dir = tempdir().unwrap();
+        let mut engine = test_engine_with_attack(attack(200, 1.0, None));
+        engine.set_player(test_player());
+        let mut current = CurrentPlayer {
+            slot: Some(0),
+            save: Some(SaveGame::new(test_player())),
+            save_dir: SaveDirectory(dir.path().to_str().unwrap().to_string()),
+        };
+        enter_combat(&mut engine);
+
+        engine.apply(GameCommand::ToggleIngredient("Vodka".to_string()));
+        let events = engine.apply(GameCommand::ValidateCocktail);
+        assert!(events.contains(&GameEvent::ArenaLost));
+        assert!(events.iter().any(GameEvent::requires_save));
+        current.save_from(&engine);
+
+        let save = JsonLoader::load_save_game(&current.save_dir.slot_path(0)).unwrap();
+        assert_eq!(save.player.caracter.hp, 80);
+    }
+
+    /// Test des HP du boss
+    /// Ils doivent venir du `Caracter` du maître et non d'une valeur fixe.
+    #[test]
+    fn test_boss_hp_comes_from_master() {
+        let mut engine = test_engine();
+        enter_combat(&mut engine);
+
+        assert_eq!(engine.boss_max_hp, 120);
+        assert_eq!(engine.boss_hp, 120);
+    }
+
+    /// Test d'une attaque ratée
+    /// Avec une précision nulle, le boss ne fait aucun dégât mais l'attaque est rapportée.
+    #[test]
+    fn test_missed_boss_attack_deals_no_damage() {
+        let mut engine = test_engine_with_attack(attack(50, 0.0, None));
+        enter_combat(&mut engine);
+
+        let events = craft_correct_cocktail(&mut engine);
+
+        assert!(events.contains(&GameEvent::BossAttacked {
+            attack: "Jet d'Anis".to_string(),
+            hit: false,
+            damage: 0,
+        }));
+        assert_eq!(engine.player_hp, 100);
+        assert!(!engine.current_boss_attack.as_ref().unwrap().hit);
+    }
+
+    /// Test des effets de statut
+    /// Le poison inflige des dégâts au tour suivant du boss puis disparaît.
+    #[test]
+    fn test_poison_ticks_on_boss_turn() {
+        let poison = StatusEffect::Poison { damage: 3, turns: 1 };
+        let mut engine = test_engine_with_attack(attack(5, 1.0, Some(poison.clone())));
+        enter_combat(&mut engine);
+
+        let events = craft_correct_cocktail(&mut engine);
+        assert!(events.contains(&GameEvent::StatusEffectApplied(poison)));
+        assert_eq!(engine.player_hp, 95);
+
+        engine.apply(GameCommand::StartFinalCraft);
+        select_instruction(&mut engine, "Ajouter l'eau");
+        select_instruction(&mut engine, "Verser le Pastis");
+        engine.apply(GameCommand::ValidateInstructionOrder);
+
+        // 15 (mauvais ordre) + 3 (poison) + 5 (attaque) ; le nouveau poison reste actif
+        assert_eq!(engine.player_hp, 72);
+        assert_eq!(engine.player_status_effects.len(), 1);
+    }
+
+    /// Test de l'affaiblissement
+    /// Un joueur affaibli inflige moins de dégâts avec son cocktail.
+    #[test]
+    fn test_weakness_reduces_player_damage() {
+        let mut engine = test_engine();
+        enter_combat(&mut engine);
+        engine
+            .player_status_effects
+            .push(StatusEffect::Weakness { factor: 0.5, turns: 1 });
+
+        craft_correct_cocktail(&mut engine);
+
+        assert_eq!(engine.boss_hp, 90);
+        assert!(engine.player_status_effects.is_empty());
+    }
+
+    /// Test de l'aptitude de boost
+    /// Elle coûte des PP et multiplie les dégâts du cocktail suivant.
+    #[test]
+    fn test_damage_boost_aptitude_multiplies_cocktail_damage() {
+        let mut engine = test_engine();
+        engine.player_aptitudes = vec![aptitude("Mixologie", 10, 1.5, AptitudeEffect::DamageBoost)];
+        enter_combat(&mut engine);
+
+        let events = engine.apply(GameCommand::UseAptitude(0));
+        assert!(events.contains(&GameEvent::AptitudeUsed {
+            name: "Mixologie".to_string(),
+            pp_left: 40,
+        }));
+        assert_eq!(engine.player_hp, 95);
+
+        craft_correct_cocktail(&mut engine);
+
+        assert_eq!(engine.boss_hp, 30);
+        assert!(engine.pending_damage_boost.is_none());
+    }
+
+    /// Test de l'aptitude de bouclier
+    /// La riposte du boss est bloquée et ne fait aucun dégât.
+    #[test]
+    fn test_shield_aptitude_blocks_next_attack() {
+        let mut engine = test_engine();
+        engine.player_aptitudes = vec![aptitude("Flair", 8, 1.0, AptitudeEffect::Shield)];
+        enter_combat(&mut engine);
+
+        engine.apply(GameCommand::UseAptitude(0));
+
+        assert_eq!(engine.player_hp, 100);
+        assert!(engine.current_boss_attack.as_ref().unwrap().blocked);
+        assert!(!engine.shield_active);
+    }
+
+    /// Test de l'aptitude de dégustation
+    /// Elle révèle autant d'ingrédients corrects que sa puissance.
+    #[test]
+    fn test_reveal_aptitude_reveals_recipe_ingredients() {
+        let mut engine = test_engine();
+        engine.player_aptitudes =
+            vec![aptitude("Degustation", 5, 1.0, AptitudeEffect::RevealIngredient)];
+        enter_combat(&mut engine);
+
+        let events = engine.apply(GameCommand::UseAptitude(0));
+        assert!(events.contains(&GameEvent::IngredientRevealed("Pastis".to_string())));
+
+        engine.apply(GameCommand::UseAptitude(0));
+        assert_eq!(engine.revealed_ingredients, vec!["Pastis", "Eau"]);
+        assert_eq!(engine.player_pp, 40);
+    }
+
+    /// Test d'une aptitude trop chère
+    /// Sans assez de PP, rien ne se passe et le boss ne joue pas.
+    #[test]
+    fn test_aptitude_rejected_without_enough_pp() {
+        let mut engine = test_engine();
+        engine.player_aptitudes = vec![aptitude("Mixologie", 80, 1.5, AptitudeEffect::DamageBoost)];
+        enter_combat(&mut engine);
+
+        let events = engine.apply(GameCommand::UseAptitude(0));
+
+        assert_eq!(events, vec![GameEvent::AptitudeRejected("Mixologie".to_string())]);
+        assert_eq!(engine.player_pp, 50);
+        assert_eq!(engine.player_hp, 100);
+        assert!(engine.current_boss_attack.is_none());
+    }
+}
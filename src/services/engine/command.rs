@@ -0,0 +1,34 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Commandes que l'interface (Bevy, tests...) envoie au moteur de jeu.
+/// Chaque commande correspond à une action du joueur.
+/// Elles sont sérialisables pour être enregistrées dans les fichiers de rejeu.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum GameCommand {
+    OpenArenaSelection,
+    ChooseArena(usize),
+    AnswerBouncer(usize),
+    TypeAnswer(String),
+    SubmitTypedAnswer,
+    ShowHint,
+    EnterArena,
+    StartArenaCombat,
+    StartCombat,
+    UseAptitude(usize),
+    ToggleIngredient(String),
+    ValidateCocktail,
+    StartFinalCraft,
+    /// Ajoute l'instruction d'index donné dans `instruction_order` à la séquence du joueur
+    SelectInstruction(usize),
+    ValidateInstructionOrder,
+    UndoInstruction,
+    ClearInstructions,
+    OpenBar,
+    ServeOrder,
+    OpenShop,
+    ChooseTrader(usize),
+    BuyIngredient(usize),
+    OpenBadgeCase,
+    BackToMain,
+    BackToArenaSelection,
+}
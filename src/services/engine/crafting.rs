@@ -0,0 +1,496 @@
+use std::collections::HashSet;
+
+use crate::models::recipe::Recipe;
+use crate::services::engine::event::GameEvent;
+use crate::services::engine::game_engine::GameEngine;
+use crate::services::engine::state::{ArenaCombatState, GameScreenType};
+
+/// Dégâts subis par le joueur pour un mauvais cocktail
+const WRONG_COCKTAIL_DAMAGE: i32 = 20;
+/// Dégâts subis par le joueur pour un ordre d'instructions entièrement faux,
+/// réduits en proportion des premières étapes justes
+const WRONG_ORDER_DAMAGE: i32 = 15;
+/// Part des HP max du boss retirée par un cocktail correct
+const COCKTAIL_DAMAGE_RATIO: f32 = 0.5;
+/// Expérience gagnée pour un cocktail correct en combat
+pub(super) const CORRECT_COCKTAIL_XP: u32 = 10;
+
+// Préparation des cocktails : ingrédients de l'inventaire et ordre des instructions
+impl GameEngine {
+    /// Quantité d'un ingrédient dans l'inventaire du joueur.
+    /// Retourne `None` sans personnage chargé : les ingrédients sont alors illimités.
+    pub fn inventory_count(&self, ingredient: &str) -> Option<u32> {
+        self.player
+            .as_ref()
+            .map(|player| player.ingredient_quantity(ingredient))
+    }
+
+    /// Ingrédients proposés pour le cocktail, triés par nom, avec leur quantité.
+    /// Sans personnage chargé, ce sont les ingrédients de la recette du maître, en quantité illimitée.
+    pub fn crafting_ingredients(&self) -> Vec<(String, Option<u32>)> {
+        let mut names: Vec<String> = match &self.player {
+            Some(player) => player
+                .inventory
+                .iter()
+                .map(|stack| stack.ingredient_id.clone())
+                .collect(),
+            None => self
+                .master_recipe
+                .iter()
+                .flat_map(|recipe| recipe.ingredients.iter().map(|item| item.ingredient.clone()))
+                .collect(),
+        };
+        names.sort();
+        names.dedup();
+
+        names
+            .into_iter()
+            .map(|name| {
+                let count = self.inventory_count(&name);
+                (name, count)
+            })
+            .collect()
+    }
+
+    /// Vérifie si les ingrédients sélectionnés correspondent exactement à la recette du maître
+    /// et si le joueur en possède les quantités demandées
+    pub fn is_cocktail_correct(&self) -> bool {
+        match &self.master_recipe {
+            Some(recipe) => self.selection_matches(recipe),
+            None => false, // Pas de recette = échec
+        }
+    }
+
+    // Vrai si les ingrédients sélectionnés sont exactement ceux de la recette
+    // et que le joueur en possède les quantités demandées
+    pub(super) fn selection_matches(&self, recipe: &Recipe) -> bool {
+        let selected: HashSet<&String> = self.current_crafting.selected_ingredients.iter().collect();
+        let expected: HashSet<&String> =
+            recipe.ingredients.iter().map(|item| &item.ingredient).collect();
+        selected == expected && self.player.as_ref().is_none_or(|player| player.can_craft(recipe))
+    }
+
+    // Ajoute ou retire un ingrédient de la sélection
+    // Un ingrédient absent de l'inventaire du joueur ne peut pas être sélectionné
+    pub(super) fn toggle_ingredient(&mut self, ingredient: String, events: &mut Vec<GameEvent>) {
+        let selected = !self.current_crafting.selected_ingredients.contains(&ingredient);
+        if selected && self.inventory_count(&ingredient) == Some(0) {
+            events.push(GameEvent::IngredientUnavailable(ingredient));
+            return;
+        }
+
+        let selected_ingredients = &mut self.current_crafting.selected_ingredients;
+
+        if selected {
+            selected_ingredients.push(ingredient.clone());
+        } else {
+            selected_ingredients.retain(|i| *i != ingredient);
+        }
+
+        events.push(GameEvent::IngredientToggled { ingredient, selected });
+    }
+
+    // Valide le cocktail en comparant avec la recette du maître
+    pub(super) fn validate_cocktail(&mut self, events: &mut Vec<GameEvent>) {
+        if self.current_screen != GameScreenType::Arena
+            || self.arena_combat_state != ArenaCombatState::PlayerTurn
+        {
+            return;
+        }
+        let correct = self.is_cocktail_correct();
+        events.push(GameEvent::CocktailValidated { correct });
+
+        // Les ingrédients versés sont consommés, que le cocktail soit bon ou non
+        let recipe = self.master_recipe.clone();
+        self.consume_selection(recipe.as_ref(), correct, events);
+
+        if correct {
+            self.current_crafting.cocktail_ready = true;
+            self.gain_xp(CORRECT_COCKTAIL_XP, events);
+            let damage = (self.boss_max_hp as f32 * COCKTAIL_DAMAGE_RATIO) as i32;
+            self.player_attack(damage, events);
+        } else {
+            self.damage_player(WRONG_COCKTAIL_DAMAGE, events);
+        }
+
+        if self.boss_hp == 0 || self.player_hp == 0 {
+            self.end_arena(events);
+            return;
+        }
+
+        self.boss_turn(events);
+        if self.player_hp == 0 {
+            self.end_arena(events);
+        } else if correct {
+            self.show_crafting_phase = true;
+            self.show_intro_screen = false;
+            self.change_screen(GameScreenType::Arena, events);
+        }
+    }
+
+    // Passe à la mise en ordre des instructions, une fois le bon cocktail trouvé
+    pub(super) fn start_final_craft(&mut self, events: &mut Vec<GameEvent>) {
+        if self.current_screen != GameScreenType::Arena
+            || self.arena_combat_state != ArenaCombatState::PlayerTurn
+            || !self.current_crafting.cocktail_ready
+        {
+            return;
+        }
+        self.show_crafting_phase = false;
+        self.change_screen(GameScreenType::InstructionOrdering, events);
+    }
+
+    // Vide la sélection d'ingrédients et les retire de l'inventaire :
+    // les quantités de la recette si elle est réussie, une unité de chaque sinon
+    pub(super) fn consume_selection(
+        &mut self,
+        recipe: Option<&Recipe>,
+        correct: bool,
+        events: &mut Vec<GameEvent>,
+    ) {
+        let selected = std::mem::take(&mut self.current_crafting.selected_ingredients);
+        let used: Vec<(String, u32)> = match (recipe, correct) {
+            (Some(recipe), true) => recipe
+                .ingredients
+                .iter()
+                .map(|item| (item.ingredient.clone(), item.quantity))
+                .collect(),
+            _ => selected.into_iter().map(|name| (name, 1)).collect(),
+        };
+        self.consume_ingredients(&used, events);
+    }
+
+    // Retire de l'inventaire du joueur les quantités utilisées de chaque ingrédient
+    fn consume_ingredients(&mut self, used: &[(String, u32)], events: &mut Vec<GameEvent>) {
+        let Some(player) = self.player.as_mut() else {
+            return;
+        };
+
+        let mut consumed = Vec::new();
+        for (name, quantity) in used {
+            let available = player.ingredient_quantity(name).min(*quantity);
+            if available > 0 && player.remove_ingredient(name, available) {
+                consumed.push(name.clone());
+            }
+        }
+        if !consumed.is_empty() {
+            events.push(GameEvent::IngredientsConsumed(consumed));
+        }
+    }
+
+    /// Instructions de la recette en cours que le joueur n'a pas encore placées dans sa séquence,
+    /// avec leur index dans `instruction_order`, dans l'ordre mélangé où elles sont proposées.
+    /// Une instruction répétée dans la recette est proposée autant de fois qu'elle apparaît.
+    pub fn remaining_instructions(&self) -> Vec<(usize, &String)> {
+        let selected = &self.current_crafting.selected_steps;
+        self.instruction_order
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !selected.contains(index))
+            .collect()
+    }
+
+    /// Séquence d'instructions choisie par le joueur, dans l'ordre du choix
+    pub fn selected_instructions(&self) -> Vec<&String> {
+        self.current_crafting
+            .selected_steps
+            .iter()
+            .filter_map(|&index| self.instruction_order.get(index))
+            .collect()
+    }
+
+    // Mélange les instructions de la recette à remettre dans l'ordre.
+    // L'ordre reste le même jusqu'à la recette suivante. La séquence du joueur est vidée,
+    // ses index désignaient l'ancien ordre.
+    pub(super) fn shuffle_instructions(&mut self, instructions: Option<Vec<String>>) {
+        self.instruction_order = instructions.unwrap_or_default();
+        self.rng.shuffle(&mut self.instruction_order);
+        self.current_crafting.selected_steps.clear();
+    }
+
+    // Ajoute une instruction à la fin de la séquence du joueur, si elle fait partie
+    // des instructions encore disponibles
+    pub(super) fn select_instruction(&mut self, index: usize, events: &mut Vec<GameEvent>) {
+        let Some(instruction) = self.instruction_order.get(index) else {
+            return;
+        };
+        let selected = &mut self.current_crafting.selected_steps;
+        if !selected.contains(&index) {
+            selected.push(index);
+            events.push(GameEvent::InstructionSelected(instruction.clone()));
+        }
+    }
+
+    // Retire la dernière instruction de la séquence du joueur
+    pub(super) fn undo_instruction(&mut self, events: &mut Vec<GameEvent>) {
+        if let Some(index) = self.current_crafting.selected_steps.pop() {
+            events.push(GameEvent::InstructionUndone(self.instruction_order[index].clone()));
+        }
+    }
+
+    // Valide l'ordre des instructions choisi par le joueur.
+    // Une séquence incomplète est refusée sans pénalité : toutes les étapes doivent être placées.
+    pub(super) fn validate_instruction_order(&mut self, events: &mut Vec<GameEvent>) {
+        if self.current_screen != GameScreenType::InstructionOrdering
+            || self.arena_combat_state != ArenaCombatState::PlayerTurn
+        {
+            return;
+        }
+        let Some(recipe) = &self.master_recipe else {
+            return;
+        };
+        if self.current_crafting.selected_steps.len() != recipe.instructions.len() {
+            return;
+        }
+
+        let selected = self.selected_instructions();
+        let correct = selected.iter().copied().eq(&recipe.instructions);
+        let correct_steps = selected
+            .into_iter()
+            .zip(&recipe.instructions)
+            .take_while(|(selected, expected)| selected == expected)
+            .count();
+        let total_steps = recipe.instructions.len().max(1) as i32;
+        let wrong_steps = total_steps - correct_steps as i32;
+        self.current_crafting.correct_steps = Some(correct_steps);
+        events.push(GameEvent::InstructionOrderValidated {
+            correct,
+            correct_steps,
+        });
+
+        if correct {
+            // Le cocktail parfait achève le boss
+            self.current_crafting.instruction_correct = true;
+            let remaining_boss_hp = self.boss_hp;
+            self.damage_boss(remaining_boss_hp, events);
+            self.end_arena(events);
+            return;
+        }
+
+        // Plus la séquence commence bien, moins l'erreur coûte cher (arrondi au supérieur)
+        self.current_crafting.instruction_correct = false;
+        let damage = (WRONG_ORDER_DAMAGE * wrong_steps + total_steps - 1) / total_steps;
+        self.damage_player(damage, events);
+        if self.player_hp > 0 {
+            self.boss_turn(events);
+        }
+
+        if self.player_hp == 0 {
+            self.end_arena(events);
+        } else {
+            self.current_crafting.selected_steps.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::engine::command::GameCommand;
+    use crate::services::engine::event::GameEvent;
+    use crate::services::engine::state::{ArenaCombatState, GameScreenType};
+    use crate::services::engine::test_support::*;
+
+    /// Test des mauvais cocktails répétés
+    /// Chaque erreur coûte 20 HP plus l'attaque du boss, jusqu'à la défaite.
+    #[test]
+    fn test_wrong_cocktails_lead_to_defeat() {
+        let mut engine = test_engine();
+        enter_combat(&mut engine);
+
+        for _ in 0..3 {
+            engine.apply(GameCommand::ToggleIngredient("Vodka".to_string()));
+            engine.apply(GameCommand::ValidateCocktail);
+        }
+        assert_eq!(engine.player_hp, 25);
+        assert_eq!(engine.current_screen, GameScreenType::Arena);
+
+        engine.apply(GameCommand::ToggleIngredient("Vodka".to_string()));
+        let events = engine.apply(GameCommand::ValidateCocktail);
+
+        assert!(events.contains(&GameEvent::ArenaLost));
+        assert_eq!(engine.player_hp, 0);
+        assert_eq!(engine.arena_combat_state, ArenaCombatState::Defeat);
+    }
+
+    /// Test d'un mauvais ordre d'instructions
+    /// Le joueur perd 15 HP, subit l'attaque du boss et sa sélection est vidée.
+    #[test]
+    fn test_wrong_instruction_order_costs_hp() {
+        let mut engine = test_engine();
+        enter_combat(&mut engine);
+        craft_correct_cocktail(&mut engine);
+        engine.apply(GameCommand::StartFinalCraft);
+
+        select_instruction(&mut engine, "Ajouter l'eau");
+        select_instruction(&mut engine, "Verser le Pastis");
+        let events = engine.apply(GameCommand::ValidateInstructionOrder);
+
+        assert!(events.contains(&GameEvent::InstructionOrderValidated {
+            correct: false,
+            correct_steps: 0
+        }));
+        assert_eq!(engine.player_hp, 75);
+        assert!(engine.current_crafting.selected_steps.is_empty());
+        assert_eq!(engine.current_screen, GameScreenType::InstructionOrdering);
+    }
+
+    /// Test d'une séquence incomplète
+    /// La validation est refusée sans pénalité tant que toutes les étapes ne sont pas placées.
+    #[test]
+    fn test_incomplete_order_is_rejected_without_penalty() {
+        let mut engine = test_engine();
+        enter_combat(&mut engine);
+        craft_correct_cocktail(&mut engine);
+        engine.apply(GameCommand::StartFinalCraft);
+        let hp = engine.player_hp;
+
+        select_instruction(&mut engine, "Verser le Pastis");
+        let events = engine.apply(GameCommand::ValidateInstructionOrder);
+
+        assert!(events.is_empty());
+        assert_eq!(engine.player_hp, hp);
+        assert_eq!(engine.current_crafting.correct_steps, None);
+        assert_eq!(engine.selected_instructions(), vec!["Verser le Pastis"]);
+    }
+
+    /// Test d'un ordre d'instructions en partie juste
+    /// Les premières étapes justes sont comptées et réduisent les dégâts subis.
+    #[test]
+    fn test_partially_right_order_reduces_damage() {
+        let mut engine = test_engine();
+        engine.masters[1].recipe.instructions.insert(1, "Remuer".to_string());
+        enter_combat(&mut engine);
+        craft_correct_cocktail(&mut engine);
+        engine.apply(GameCommand::StartFinalCraft);
+
+        select_instruction(&mut engine, "Verser le Pastis");
+        select_instruction(&mut engine, "Ajouter l'eau");
+        select_instruction(&mut engine, "Remuer");
+        let events = engine.apply(GameCommand::ValidateInstructionOrder);
+
+        assert!(events.contains(&GameEvent::InstructionOrderValidated {
+            correct: false,
+            correct_steps: 1
+        }));
+        assert!(events.contains(&GameEvent::PlayerDamaged {
+            amount: 10,
+            remaining: 85
+        }));
+        assert_eq!(engine.current_crafting.correct_steps, Some(1));
+        assert_eq!(engine.player_hp, 80);
+    }
+
+    /// Test d'une recette qui répète une instruction
+    /// Chaque occurrence est proposée séparément, la recette peut donc être complétée,
+    /// et un index déjà choisi ou inconnu est ignoré.
+    #[test]
+    fn test_repeated_instruction_can_be_completed() {
+        let mut engine = test_engine();
+        engine.masters[1].recipe.instructions =
+            vec!["Remuer".to_string(), "Verser le Pastis".to_string(), "Remuer".to_string()];
+        enter_combat(&mut engine);
+        craft_correct_cocktail(&mut engine);
+        engine.apply(GameCommand::StartFinalCraft);
+
+        let (first, _) = engine.remaining_instructions()[0];
+        engine.apply(GameCommand::SelectInstruction(first));
+        assert!(engine.apply(GameCommand::SelectInstruction(first)).is_empty());
+        assert!(engine.apply(GameCommand::SelectInstruction(3)).is_empty());
+        engine.apply(GameCommand::UndoInstruction);
+
+        select_instruction(&mut engine, "Remuer");
+        select_instruction(&mut engine, "Verser le Pastis");
+        assert_eq!(engine.remaining_instructions().len(), 1);
+        select_instruction(&mut engine, "Remuer");
+        let events = engine.apply(GameCommand::ValidateInstructionOrder);
+
+        assert!(events.contains(&GameEvent::ArenaWon));
+    }
+
+    /// Test de l'annulation de la dernière étape choisie
+    /// L'étape retirée de la séquence est de nouveau proposée au joueur.
+    #[test]
+    fn test_undo_removes_last_instruction() {
+        let mut engine = test_engine();
+        enter_combat(&mut engine);
+        craft_correct_cocktail(&mut engine);
+        engine.apply(GameCommand::StartFinalCraft);
+        assert_eq!(engine.remaining_instructions().len(), 2);
+
+        select_instruction(&mut engine, "Verser le Pastis");
+        select_instruction(&mut engine, "Ajouter l'eau");
+        assert!(engine.remaining_instructions().is_empty());
+
+        let events = engine.apply(GameCommand::UndoInstruction);
+        assert_eq!(events, vec![GameEvent::InstructionUndone("Ajouter l'eau".to_string())]);
+        assert_eq!(engine.selected_instructions(), vec!["Verser le Pastis"]);
+        let remaining: Vec<&String> = engine
+            .remaining_instructions()
+            .into_iter()
+            .map(|(_, instruction)| instruction)
+            .collect();
+        assert_eq!(remaining, vec!["Ajouter l'eau"]);
+
+        engine.apply(GameCommand::UndoInstruction);
+        assert!(engine.apply(GameCommand::UndoInstruction).is_empty());
+    }
+
+    /// Test des ingrédients tirés de l'inventaire du joueur
+    /// Un ingrédient absent ne peut pas être sélectionné, et les ingrédients versés
+    /// sont consommés même quand le cocktail est raté.
+    #[test]
+    fn test_crafting_consumes_player_inventory() {
+        let mut engine = test_engine();
+        engine.set_player(test_player());
+        enter_combat(&mut engine);
+
+        assert_eq!(
+            engine.crafting_ingredients(),
+            vec![
+                ("Eau".to_string(), Some(1)),
+                ("Pastis".to_string(), Some(2)),
+                ("Vodka".to_string(), Some(1)),
+            ]
+        );
+        let events = engine.apply(GameCommand::ToggleIngredient("Rhum".to_string()));
+        assert_eq!(events, vec![GameEvent::IngredientUnavailable("Rhum".to_string())]);
+        assert!(engine.current_crafting.selected_ingredients.is_empty());
+
+        engine.apply(GameCommand::ToggleIngredient("Vodka".to_string()));
+        engine.apply(GameCommand::ToggleIngredient("Pastis".to_string()));
+        let events = engine.apply(GameCommand::ValidateCocktail);
+
+        assert!(events.contains(&GameEvent::CocktailValidated { correct: false }));
+        assert!(events.contains(&GameEvent::IngredientsConsumed(vec![
+            "Vodka".to_string(),
+            "Pastis".to_string()
+        ])));
+        assert_eq!(engine.inventory_count("Vodka"), Some(0));
+        assert_eq!(engine.inventory_count("Pastis"), Some(1));
+        let events = engine.apply(GameCommand::ToggleIngredient("Vodka".to_string()));
+        assert_eq!(events, vec![GameEvent::IngredientUnavailable("Vodka".to_string())]);
+    }
+
+    /// Test des quantités de la recette
+    /// Le bon cocktail n'est réussi qu'avec assez de chaque ingrédient,
+    /// et il consomme les quantités de la recette.
+    #[test]
+    fn test_correct_cocktail_needs_recipe_quantities() {
+        let mut engine = test_engine();
+        let mut player = test_player();
+        player.remove_ingredient("Pastis", 1);
+        engine.set_player(player);
+        enter_combat(&mut engine);
+
+        let events = craft_correct_cocktail(&mut engine);
+        assert!(events.contains(&GameEvent::CocktailValidated { correct: false }));
+        assert_eq!(engine.inventory_count("Pastis"), Some(0));
+
+        engine.player.as_mut().unwrap().add_ingredient("Pastis", 2);
+        engine.player.as_mut().unwrap().add_ingredient("Eau", 1);
+        let events = craft_correct_cocktail(&mut engine);
+        assert!(events.contains(&GameEvent::CocktailValidated { correct: true }));
+        assert_eq!(engine.inventory_count("Pastis"), Some(0));
+        assert_eq!(engine.inventory_count("Eau"), Some(0));
+    }
+}
@@ -0,0 +1,56 @@
+use crate::models::attack::StatusEffect;
+use crate::services::engine::state::GameScreenType;
+
+/// Événements produits par le moteur de jeu en réponse à une commande.
+/// L'interface s'en sert pour savoir quoi rafraîchir ou afficher.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    ScreenChanged(GameScreenType),
+    ArenaChosen(String),
+    ArenaLocked(String),
+    BouncerAnswered { correct: bool },
+    AnswerTyped(String),
+    HintRevealed(String),
+    MasterLoaded(String),
+    IngredientToggled { ingredient: String, selected: bool },
+    IngredientUnavailable(String),
+    IngredientsConsumed(Vec<String>),
+    CocktailValidated { correct: bool },
+    PlayerDamaged { amount: i32, remaining: i32 },
+    BossDamaged { amount: i32, remaining: i32 },
+    InstructionSelected(String),
+    InstructionUndone(String),
+    InstructionsCleared,
+    InstructionOrderValidated { correct: bool, correct_steps: usize },
+    ArenaWon,
+    ArenaLost,
+    BadgeWon(String),
+    ChampionshipWon,
+    XpGained { amount: u32, total: u32 },
+    LevelUp(u32),
+    AptitudeLearned(String),
+    BossAttacked { attack: String, hit: bool, damage: i32 },
+    StatusEffectApplied(StatusEffect),
+    AptitudeUsed { name: String, pp_left: i32 },
+    AptitudeRejected(String),
+    IngredientRevealed(String),
+    ClientArrived(String),
+    OrderServed { client: String, correct: bool, tip: i32 },
+    TraderChosen(String),
+    IngredientBought { ingredient: String, bankroll: i32 },
+    PurchaseRejected(String),
+}
+
+impl GameEvent {
+    /// Vrai si l'événement change la progression du joueur et doit être sauvegardé :
+    /// fin d'arène, achat ou commande servie au bar
+    pub fn requires_save(&self) -> bool {
+        matches!(
+            self,
+            GameEvent::ArenaWon
+                | GameEvent::ArenaLost
+                | GameEvent::IngredientBought { .. }
+                | GameEvent::OrderServed { .. }
+        )
+    }
+}
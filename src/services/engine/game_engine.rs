@@ -0,0 +1,483 @@
+use crate::models::aptitude::Aptitude;
+use crate::models::arena::Arena;
+use crate::models::attack::{Attack, StatusEffect};
+use crate::models::caracter::bouncer::{AnswerMode, Bouncer};
+use crate::models::caracter::client::Client;
+use crate::models::caracter::master::Master;
+use crate::models::caracter::player::Player;
+use crate::models::caracter::trader::Trader;
+use crate::models::level::{level_for_xp, LevelStep};
+use crate::models::recipe::Recipe;
+use crate::models::save_game::SaveGame;
+use crate::services::content::GameContent;
+use crate::services::engine::command::GameCommand;
+use crate::services::engine::event::GameEvent;
+use crate::services::engine::rng::GameRng;
+use crate::services::engine::state::{
+    ArenaCombatState, BossAttackReport, CurrentCocktailCrafting, GameScreenType,
+};
+
+/// HP du joueur au début d'un combat quand aucun personnage n'est chargé
+const PLAYER_BASE_HP: i32 = 100;
+/// PP du joueur au début d'un combat quand aucun personnage n'est chargé
+const PLAYER_BASE_PP: i32 = 50;
+
+/// Moteur de jeu indépendant de l'interface.
+/// Il contient toutes les règles d'une arène (videur, ingrédients, ordre des instructions, fin)
+/// et se pilote uniquement avec des `GameCommand`, en retournant des `GameEvent`.
+/// Le combat se joue au tour par tour : après chaque action du joueur (cocktail, ordre
+/// des instructions ou aptitude), le maître riposte avec une de ses attaques.
+///
+/// Les règles sont réparties par thème dans les modules voisins : `arenas` (sélection,
+/// videur, déblocage), `badges`, `combat`, `crafting`, `shop`, `bar` et `progression`.
+///
+/// # Exemple
+/// ```
+/// let content = GameContent::load("assets").unwrap();
+/// let mut engine = GameEngine::from_content(&content, None, Some(42));
+/// let events = engine.apply(GameCommand::OpenArenaSelection);
+/// assert!(events.contains(&GameEvent::ScreenChanged(GameScreenType::ArenaSelection)));
+/// ```
+#[derive(Default)]
+pub struct GameEngine {
+    pub(super) masters: Vec<Master>,
+    pub(super) bouncers: Vec<Bouncer>,
+    pub player: Option<Player>,
+    pub current_screen: GameScreenType,
+    pub bouncer_name: Option<String>,
+    pub current_question: String,
+    pub current_hint: Option<String>,
+    pub hint_revealed: bool,
+    pub answer_mode: AnswerMode,
+    pub answer_options: Vec<String>,
+    pub correct_answer: String,
+    pub accepted_answers: Vec<String>,
+    pub typed_answer: String,
+    pub arenas: Vec<Arena>,
+    pub beaten_arenas: Vec<String>,
+    pub selected_arena: Option<String>,
+    pub wrong_answer_message: bool,
+    pub locked_arena_message: Option<String>,
+    pub master_name: Option<String>,
+    pub master_style: Option<String>,
+    pub master_attacks: Vec<Attack>,
+    pub master_dialogs: Vec<String>,
+    pub master_badge: Option<String>,
+    pub selected_arena_index: Option<usize>,
+    pub arena_combat_state: ArenaCombatState,
+    pub player_hp: i32,
+    pub boss_hp: i32,
+    pub boss_max_hp: i32,
+    pub player_status_effects: Vec<StatusEffect>,
+    pub player_pp: i32,
+    pub player_aptitudes: Vec<Aptitude>,
+    pub pending_damage_boost: Option<f32>,
+    pub shield_active: bool,
+    pub revealed_ingredients: Vec<String>,
+    pub aptitude_message: Option<String>,
+    pub show_intro_screen: bool,
+    pub master_recipe: Option<Recipe>,
+    pub current_boss_attack: Option<BossAttackReport>,
+    pub current_crafting: CurrentCocktailCrafting,
+    /// Instructions de la recette en cours, mélangées une fois quand elle apparaît
+    pub instruction_order: Vec<String>,
+    pub show_crafting_phase: bool,
+    pub traders: Vec<Trader>,
+    pub selected_trader: Option<usize>,
+    pub shop_message: Option<String>,
+    pub(super) clients: Vec<Client>,
+    pub(super) next_client_index: usize,
+    pub current_client: Option<Client>,
+    pub bar_gestures: u32,
+    pub bar_message: Option<String>,
+    pub(super) level_curve: Vec<LevelStep>,
+    pub(super) aptitude_catalog: Vec<Aptitude>,
+    pub rng: GameRng,
+}
+
+impl GameEngine {
+    /// Crée un moteur de jeu à partir des données chargées depuis les JSON.
+    /// Les arènes sont triées par difficulté, leur maître et leur videur sont retrouvés par `id`.
+    ///
+    /// # Arguments
+    /// * `arenas` - Les arènes disponibles.
+    /// * `masters` - Les maîtres d'arène.
+    /// * `bouncers` - Les videurs qui gardent l'entrée des arènes.
+    /// * `traders` - Les marchands de la boutique.
+    /// * `clients` - Les clients servis au bar entre deux arènes.
+    pub fn new(
+        mut arenas: Vec<Arena>,
+        masters: Vec<Master>,
+        bouncers: Vec<Bouncer>,
+        traders: Vec<Trader>,
+        clients: Vec<Client>,
+    ) -> Self {
+        arenas.sort_by_key(|arena| arena.difficulty);
+
+        Self {
+            masters,
+            bouncers,
+            arenas,
+            traders,
+            clients,
+            ..Default::default()
+        }
+    }
+
+    /// Prépare le moteur pour une partie : tout le contenu du jeu, puis le joueur et la
+    /// progression de la sauvegarde du slot s'il y en a une. Les aptitudes connues du joueur
+    /// reprennent leur effet et leur coût depuis `aptitudes.json`.
+    /// Sans sauvegarde, toutes les aptitudes sont disponibles en combat.
+    /// Le hasard reprend la graine donnée, sinon celle enregistrée dans la sauvegarde ;
+    /// sans l'une ni l'autre, il est tiré d'une graine aléatoire.
+    ///
+    /// # Arguments
+    /// * `content` - Le contenu du jeu chargé au démarrage.
+    /// * `save` - La sauvegarde du slot choisi.
+    /// * `seed` - La graine du hasard, pour rejouer une partie à l'identique.
+    pub fn from_content(
+        content: &GameContent,
+        save: Option<&SaveGame>,
+        seed: Option<u64>,
+    ) -> Self {
+        let mut engine = Self::new(
+            content.arenas.clone(),
+            content.masters.clone(),
+            content.bouncers.clone(),
+            content.traders.clone(),
+            content.clients.clone(),
+        );
+        if let Some(seed) = seed.or_else(|| save.and_then(|save| save.progress.seed)) {
+            engine.rng = GameRng::new(seed);
+        }
+        engine.set_progression(content.levels.clone(), content.aptitudes.clone());
+
+        match save {
+            Some(save) => {
+                let mut player = save.player.clone();
+                for aptitude in player.aptitudes.iter_mut() {
+                    if let Some(data) = content.aptitudes.iter().find(|a| a.name == aptitude.name) {
+                        *aptitude = data.clone();
+                    }
+                }
+                engine.set_player(player);
+                engine.beaten_arenas = save.progress.beaten_arenas.clone();
+            }
+            None => engine.player_aptitudes = content.aptitudes.clone(),
+        }
+        engine
+    }
+
+    /// Charge le personnage du joueur dans le moteur.
+    /// Ses HP et PP maximum et ses aptitudes sont utilisés pour les combats, et sa progression
+    /// (badges gagnés, réputation, expérience) est mise à jour au fil de la partie.
+    /// Ses HP et PP maximum sont recalculés depuis la courbe de niveaux si elle est connue.
+    ///
+    /// # Arguments
+    /// * `player` - Le joueur chargé depuis sa sauvegarde.
+    pub fn set_player(&mut self, mut player: Player) {
+        if let Some(step) = level_for_xp(&self.level_curve, player.xp) {
+            player.apply_level(step);
+        }
+        self.player_aptitudes = player.aptitudes.clone();
+        self.player_hp = player.max_hp;
+        self.player_pp = player.max_pp;
+        self.player = Some(player);
+    }
+
+    /// Entre directement dans le combat d'une arène, sans passer par son videur
+    /// ni vérifier qu'elle est débloquée. Sert à tester une arène rapidement.
+    /// Retourne une liste vide si aucune arène n'a cet id.
+    ///
+    /// # Arguments
+    /// * `arena_id` - L'id de l'arène dans `arena.json`.
+    pub fn start_arena(&mut self, arena_id: &str) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+        let Some(arena_index) = self.arenas.iter().position(|arena| arena.id == arena_id) else {
+            return events;
+        };
+
+        let arena_name = self.arenas[arena_index].name.clone();
+        self.selected_arena = Some(arena_name.clone());
+        self.selected_arena_index = Some(arena_index);
+        events.push(GameEvent::ArenaChosen(arena_name));
+
+        self.load_master_data(&mut events);
+        self.reset_combat();
+        self.change_screen(GameScreenType::Arena, &mut events);
+        events
+    }
+
+    /// Applique une commande du joueur et retourne les événements produits.
+    /// Une liste vide signifie que la commande n'a rien changé.
+    ///
+    /// # Arguments
+    /// * `command` - La commande à appliquer.
+    pub fn apply(&mut self, command: GameCommand) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+
+        match command {
+            GameCommand::OpenArenaSelection | GameCommand::BackToArenaSelection => {
+                self.wrong_answer_message = false;
+                self.locked_arena_message = None;
+                self.change_screen(GameScreenType::ArenaSelection, &mut events);
+            }
+            GameCommand::ChooseArena(arena_index) => {
+                self.choose_arena(arena_index, &mut events);
+            }
+            GameCommand::ShowHint => {
+                self.show_hint(&mut events);
+            }
+            GameCommand::AnswerBouncer(answer_index) => {
+                self.answer_choice(answer_index, &mut events);
+            }
+            GameCommand::TypeAnswer(text) => {
+                self.type_answer(text, &mut events);
+            }
+            GameCommand::SubmitTypedAnswer => {
+                self.submit_typed_answer(&mut events);
+            }
+            GameCommand::EnterArena | GameCommand::StartCombat => {
+                self.reset_combat();
+                self.change_screen(GameScreenType::Arena, &mut events);
+            }
+            GameCommand::StartArenaCombat => {
+                self.start_arena_combat(&mut events);
+            }
+            GameCommand::UseAptitude(aptitude_index) => {
+                self.use_aptitude(aptitude_index, &mut events);
+            }
+            GameCommand::ToggleIngredient(ingredient) => {
+                self.toggle_ingredient(ingredient, &mut events);
+                self.count_bar_gesture(&events);
+            }
+            GameCommand::ValidateCocktail => {
+                self.validate_cocktail(&mut events);
+            }
+            GameCommand::StartFinalCraft => {
+                self.start_final_craft(&mut events);
+            }
+            GameCommand::SelectInstruction(index) => {
+                self.select_instruction(index, &mut events);
+                self.count_bar_gesture(&events);
+            }
+            GameCommand::ValidateInstructionOrder => {
+                self.validate_instruction_order(&mut events);
+            }
+            GameCommand::UndoInstruction => {
+                self.undo_instruction(&mut events);
+                self.count_bar_gesture(&events);
+            }
+            GameCommand::ClearInstructions => {
+                self.current_crafting.selected_steps.clear();
+                events.push(GameEvent::InstructionsCleared);
+            }
+            GameCommand::OpenBar => {
+                self.open_bar(&mut events);
+            }
+            GameCommand::ServeOrder => {
+                self.serve_order(&mut events);
+            }
+            GameCommand::OpenShop => {
+                self.shop_message = None;
+                self.change_screen(GameScreenType::Shop, &mut events);
+            }
+            GameCommand::ChooseTrader(trader_index) => {
+                self.choose_trader(trader_index, &mut events);
+            }
+            GameCommand::BuyIngredient(ingredient_index) => {
+                self.buy_ingredient(ingredient_index, &mut events);
+            }
+            GameCommand::OpenBadgeCase => {
+                self.change_screen(GameScreenType::BadgeCase, &mut events);
+            }
+            GameCommand::BackToMain => {
+                self.change_screen(GameScreenType::Main, &mut events);
+            }
+        }
+
+        events
+    }
+
+    /// Réinitialise l'état du combat
+    /// Le joueur commence chaque combat reposé, avec ses HP et PP maximum.
+    pub fn reset_combat(&mut self) {
+        self.arena_combat_state = ArenaCombatState::Start;
+        self.player_hp = self
+            .player
+            .as_ref()
+            .map_or(PLAYER_BASE_HP, |player| player.max_hp);
+        self.boss_hp = self.boss_max_hp;
+        self.show_intro_screen = true;
+        self.show_crafting_phase = false;
+        self.current_boss_attack = None;
+        self.player_status_effects.clear();
+        self.player_pp = self
+            .player
+            .as_ref()
+            .map_or(PLAYER_BASE_PP, |player| player.max_pp);
+        self.pending_damage_boost = None;
+        self.shield_active = false;
+        self.revealed_ingredients.clear();
+        self.aptitude_message = None;
+        self.current_crafting = CurrentCocktailCrafting::default();
+    }
+
+    /// Arène actuellement sélectionnée
+    pub fn selected_arena_data(&self) -> Option<&Arena> {
+        self.selected_arena_index
+            .and_then(|index| self.arenas.get(index))
+    }
+
+    // Maître de l'arène sélectionnée, retrouvé par son id
+    pub(super) fn selected_master(&self) -> Option<&Master> {
+        let arena = self.selected_arena_data()?;
+        self.masters.iter().find(|master| master.id == arena.master_id)
+    }
+
+    // Change l'écran courant et émet l'événement correspondant
+    pub(super) fn change_screen(&mut self, screen: GameScreenType, events: &mut Vec<GameEvent>) {
+        self.current_screen = screen;
+        if screen == GameScreenType::InstructionOrdering {
+            let recipe = self.master_recipe.as_ref();
+            self.shuffle_instructions(recipe.map(|recipe| recipe.instructions.clone()));
+        }
+        events.push(GameEvent::ScreenChanged(screen));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::engine::test_support::*;
+
+    /// Test d'une partie complète gagnée
+    /// Ce test enchaîne videur, ingrédients et ordre des instructions jusqu'à l'écran de fin.
+    /// Le cocktail correct retire la moitié des HP du boss, qui riposte une fois.
+    #[test]
+    fn test_full_arena_flow_victory() {
+        let mut engine = test_engine();
+        enter_combat(&mut engine);
+        assert_eq!(engine.master_name.as_deref(), Some("Pastis Man"));
+        assert_eq!(engine.arena_combat_state, ArenaCombatState::PlayerTurn);
+
+        let events = craft_correct_cocktail(&mut engine);
+        assert!(events.contains(&GameEvent::CocktailValidated { correct: true }));
+        assert!(engine.show_crafting_phase);
+        assert_eq!(engine.boss_hp, 60);
+        assert_eq!(engine.player_hp, 95);
+
+        engine.apply(GameCommand::StartFinalCraft);
+        assert_eq!(engine.current_screen, GameScreenType::InstructionOrdering);
+        select_instruction(&mut engine, "Verser le Pastis");
+        select_instruction(&mut engine, "Ajouter l'eau");
+        let events = engine.apply(GameCommand::ValidateInstructionOrder);
+
+        assert!(events.contains(&GameEvent::ArenaWon));
+        assert_eq!(engine.current_screen, GameScreenType::ArenaEnd);
+        assert_eq!(engine.arena_combat_state, ArenaCombatState::Victory);
+        assert_eq!(engine.boss_hp, 0);
+        assert_eq!(engine.player_hp, 95);
+    }
+
+    /// Test des commandes de combat envoyées hors de l'arène
+    /// Depuis l'écran principal, elles ne produisent aucun événement et ne changent rien.
+    #[test]
+    fn test_combat_commands_ignored_outside_arena() {
+        let mut engine = test_engine();
+        let boss_hp = engine.boss_hp;
+        let player_hp = engine.player_hp;
+
+        for command in [
+            GameCommand::StartArenaCombat,
+            GameCommand::ValidateCocktail,
+            GameCommand::StartFinalCraft,
+            GameCommand::ValidateInstructionOrder,
+        ] {
+            assert!(engine.apply(command).is_empty());
+            assert_eq!(engine.current_screen, GameScreenType::Main);
+            assert_eq!(engine.arena_combat_state, ArenaCombatState::Start);
+        }
+        assert_eq!(engine.boss_hp, boss_hp);
+        assert_eq!(engine.player_hp, player_hp);
+        assert!(engine.beaten_arenas.is_empty());
+    }
+
+    /// Test d'un combat avec un personnage chargé
+    /// Les HP, PP et aptitudes du combat viennent du joueur.
+    #[test]
+    fn test_loaded_player_drives_combat_stats() {
+        let mut engine = test_engine();
+        engine.set_player(test_player());
+
+        enter_combat(&mut engine);
+
+        assert_eq!(engine.player_hp, 80);
+        assert_eq!(engine.player_pp, 30);
+        assert_eq!(engine.player_aptitudes[0].name, "Mixologie");
+    }
+
+    /// Test de l'entrée directe dans une arène
+    /// Le combat commence sans videur ni déblocage, et un id inconnu ne change rien.
+    #[test]
+    fn test_start_arena_skips_bouncer_and_locks() {
+        let mut engine = test_engine();
+
+        let events = engine.start_arena("cidre");
+        assert!(events.contains(&GameEvent::MasterLoaded("Cidre Lord".to_string())));
+        assert_eq!(engine.current_screen, GameScreenType::Arena);
+        assert!(engine.show_intro_screen);
+        assert_eq!(engine.boss_hp, 200);
+
+        assert!(engine.start_arena("inconnue").is_empty());
+        assert_eq!(engine.master_name.as_deref(), Some("Cidre Lord"));
+    }
+
+    /// Test d'une partie rejouée avec la même graine
+    /// Les réponses du videur, les attaques du boss et l'ordre des instructions sont
+    /// identiques, et l'ordre des instructions ne change pas pendant la remise en ordre.
+    #[test]
+    fn test_same_seed_replays_same_game() {
+        let play = || {
+            let mut engine = test_engine_with_attack(attack(5, 0.5, None));
+            engine.rng = GameRng::new(7);
+            engine.apply(GameCommand::OpenArenaSelection);
+            engine.apply(GameCommand::ChooseArena(0));
+            let options = engine.answer_options.clone();
+            answer_bouncer_correctly(&mut engine);
+            engine.apply(GameCommand::EnterArena);
+            engine.apply(GameCommand::StartArenaCombat);
+
+            let mut hp = Vec::new();
+            for _ in 0..2 {
+                engine.apply(GameCommand::ToggleIngredient("Vodka".to_string()));
+                engine.apply(GameCommand::ValidateCocktail);
+                hp.push(engine.player_hp);
+            }
+            craft_correct_cocktail(&mut engine);
+            engine.apply(GameCommand::StartFinalCraft);
+            let order = engine.instruction_order.clone();
+            engine.apply(GameCommand::SelectInstruction(0));
+            assert_eq!(engine.instruction_order, order);
+            (options, hp, order)
+        };
+
+        assert_eq!(play(), play());
+    }
+
+    /// Test de la graine d'une sauvegarde
+    /// Le hasard reprend la graine enregistrée dans la sauvegarde, sauf si une graine
+    /// est donnée en ligne de commande.
+    #[test]
+    fn test_saved_seed_is_restored() {
+        let content = GameContent::load("assets").unwrap();
+        let mut save = SaveGame::new(test_player());
+        save.progress.seed = Some(42);
+
+        let restored = GameEngine::from_content(&content, Some(&save), None);
+        let overridden = GameEngine::from_content(&content, Some(&save), Some(7));
+
+        assert_eq!(restored.rng.seed(), 42);
+        assert_eq!(overridden.rng.seed(), 7);
+    }
+}
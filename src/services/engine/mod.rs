@@ -0,0 +1,23 @@
+pub mod answer;
+pub mod command;
+pub mod event;
+pub mod game_engine;
+pub mod rng;
+pub mod state;
+
+// Règles du moteur, réparties par thème sur `GameEngine`
+mod arenas;
+mod badges;
+mod bar;
+mod combat;
+mod crafting;
+mod progression;
+mod shop;
+
+// Données de test partagées par les tests des modules du moteur
+#[cfg(test)]
+mod test_support;
+
+// Exports publics du moteur de jeu (aucun type Bevy ici)
+pub use command::GameCommand;
+pub use game_engine::GameEngine;
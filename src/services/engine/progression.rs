@@ -0,0 +1,170 @@
+use crate::models::aptitude::Aptitude;
+use crate::models::level::{level_for_xp, LevelStep};
+use crate::services::engine::event::GameEvent;
+use crate::services::engine::game_engine::GameEngine;
+
+/// Réputation gagnée en battant le maître d'une arène
+const ARENA_VICTORY_REPUTATION: u32 = 10;
+/// Expérience gagnée en battant le maître d'une arène
+const ARENA_VICTORY_XP: u32 = 50;
+
+// Progression du joueur : récompenses de victoire, expérience et niveaux
+impl GameEngine {
+    /// Donne au moteur la courbe de niveaux et les aptitudes que le joueur peut apprendre
+    /// en montant de niveau. Sans courbe, le joueur gagne de l'expérience sans changer de niveau.
+    ///
+    /// # Arguments
+    /// * `level_curve` - Les paliers de niveaux chargés depuis `levels.json`.
+    /// * `aptitudes` - Les aptitudes du jeu, apprises dans l'ordre quand un emplacement se libère.
+    pub fn set_progression(&mut self, level_curve: Vec<LevelStep>, aptitudes: Vec<Aptitude>) {
+        self.level_curve = level_curve;
+        self.aptitude_catalog = aptitudes;
+    }
+
+    /// Expérience requise pour le prochain niveau du joueur, `None` au niveau maximum
+    pub fn next_level_xp(&self) -> Option<u32> {
+        let level = self.player.as_ref()?.level;
+        self.level_curve
+            .iter()
+            .filter(|step| step.level > level)
+            .map(|step| step.xp)
+            .min()
+    }
+
+    // Donne au joueur le badge du maître vaincu et de la réputation
+    pub(super) fn reward_player(&mut self, events: &mut Vec<GameEvent>) {
+        let badge = self.selected_master().map(|master| master.badge.clone());
+        let Some(player) = self.player.as_mut() else {
+            return;
+        };
+
+        player.reputation += ARENA_VICTORY_REPUTATION;
+        if let Some(badge) = badge {
+            if !player.badges_won.iter().any(|won| won.name == badge.name) {
+                events.push(GameEvent::BadgeWon(badge.name.clone()));
+                player.badges_won.push(badge);
+            }
+        }
+        self.gain_xp(ARENA_VICTORY_XP, events);
+    }
+
+    // Ajoute de l'expérience au joueur et applique les montées de niveau atteintes :
+    // les HP et PP maximum augmentent à partir du prochain combat, et chaque nouvel
+    // emplacement d'aptitude est rempli par la prochaine aptitude du catalogue
+    pub(super) fn gain_xp(&mut self, amount: u32, events: &mut Vec<GameEvent>) {
+        let Some(player) = self.player.as_mut() else {
+            return;
+        };
+        if amount == 0 {
+            return;
+        }
+
+        player.xp += amount;
+        events.push(GameEvent::XpGained {
+            amount,
+            total: player.xp,
+        });
+
+        let Some(step) = level_for_xp(&self.level_curve, player.xp).cloned() else {
+            return;
+        };
+        if step.level <= player.level {
+            return;
+        }
+
+        player.apply_level(&step);
+        events.push(GameEvent::LevelUp(step.level));
+
+        while player.aptitudes.len() < step.aptitude_slots {
+            let Some(aptitude) = self
+                .aptitude_catalog
+                .iter()
+                .find(|aptitude| !player.aptitudes.iter().any(|known| known.name == aptitude.name))
+                .cloned()
+            else {
+                break;
+            };
+            events.push(GameEvent::AptitudeLearned(aptitude.name.clone()));
+            player.aptitudes.push(aptitude.clone());
+            self.player_aptitudes.push(aptitude);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::aptitude::AptitudeEffect;
+    use crate::services::engine::crafting::CORRECT_COCKTAIL_XP;
+    use crate::services::engine::test_support::*;
+
+    /// Test de la récompense de victoire
+    /// Le joueur gagne le badge du maître une seule fois et de la réputation à chaque victoire.
+    #[test]
+    fn test_victory_rewards_badge_and_reputation() {
+        let mut engine = test_engine();
+        let mut player = test_player();
+        // De quoi préparer le cocktail des deux combats
+        player.inventory = vec![stack("Pastis", 4), stack("Eau", 2)];
+        engine.set_player(player);
+
+        let badge_won = GameEvent::BadgeWon("Pastis Badge".to_string());
+        assert!(win_arena(&mut engine).contains(&badge_won));
+        assert!(!win_arena(&mut engine).contains(&badge_won));
+
+        let player = engine.player.as_ref().unwrap();
+        assert_eq!(player.badges_won.len(), 1);
+        assert_eq!(player.badges_won[0].name, "Pastis Badge");
+        assert_eq!(player.reputation, 2 * ARENA_VICTORY_REPUTATION);
+    }
+
+    /// Test du gain d'expérience et de la montée de niveau
+    /// Une victoire fait passer le joueur au niveau 2 : ses HP et PP maximum augmentent
+    /// sans remplir ses HP actuels, et il apprend l'aptitude suivante du catalogue.
+    #[test]
+    fn test_victory_gives_xp_and_levels_up() {
+        let mut engine = test_engine();
+        engine.set_progression(
+            test_level_curve(),
+            vec![
+                aptitude("Mixologie", 10, 2.0, AptitudeEffect::DamageBoost),
+                aptitude("Bouclier", 15, 1.0, AptitudeEffect::Shield),
+            ],
+        );
+        engine.set_player(test_player());
+        assert_eq!(engine.player.as_ref().unwrap().max_hp, 100);
+        assert_eq!(engine.next_level_xp(), Some(40));
+
+        let events = win_arena(&mut engine);
+        let hp_after_combat = engine.player_hp;
+
+        assert!(events.contains(&GameEvent::XpGained {
+            amount: ARENA_VICTORY_XP,
+            total: CORRECT_COCKTAIL_XP + ARENA_VICTORY_XP,
+        }));
+        assert!(events.contains(&GameEvent::LevelUp(2)));
+        assert!(events.contains(&GameEvent::AptitudeLearned("Bouclier".to_string())));
+        let player = engine.player.as_ref().unwrap();
+        assert_eq!(player.level, 2);
+        assert_eq!((player.max_hp, player.max_pp), (120, 60));
+        assert_eq!(player.caracter.hp, hp_after_combat);
+        assert_eq!(engine.player_hp, hp_after_combat);
+        assert_eq!(player.aptitudes.len(), 2);
+        assert_eq!(engine.next_level_xp(), None);
+    }
+
+    /// Test de l'expérience sans courbe de niveaux
+    /// Le joueur cumule l'expérience sans changer de niveau.
+    #[test]
+    fn test_xp_without_level_curve_keeps_level() {
+        let mut engine = test_engine();
+        engine.set_player(test_player());
+
+        let events = win_arena(&mut engine);
+
+        assert!(!events.iter().any(|event| matches!(event, GameEvent::LevelUp(_))));
+        let player = engine.player.as_ref().unwrap();
+        assert_eq!(player.xp, CORRECT_COCKTAIL_XP + ARENA_VICTORY_XP);
+        assert_eq!(player.level, 1);
+    }
+}
@@ -0,0 +1,73 @@
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{Rng, SeedableRng};
+
+/// Générateur aléatoire de la partie.
+/// Tout le hasard du jeu (énigmes, ordre des réponses et des instructions, attaques du boss,
+/// conseils des clients) passe par lui : avec la même graine et les mêmes actions du joueur,
+/// une partie se rejoue à l'identique.
+#[derive(Debug, Clone)]
+pub struct GameRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl GameRng {
+    /// Crée un générateur à partir d'une graine
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Graine utilisée par ce générateur, à afficher ou sauvegarder pour rejouer la partie
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Mélange une liste sur place
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        items.shuffle(&mut self.rng);
+    }
+
+    /// Choisit un élément au hasard, `None` si la liste est vide
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        items.choose(&mut self.rng)
+    }
+
+    /// Vrai avec la probabilité donnée, entre 0 et 1
+    pub fn chance(&mut self, probability: f32) -> bool {
+        self.rng.random::<f32>() < probability
+    }
+}
+
+/// Sans graine imposée, on en tire une au hasard
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new(rand::random())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test de la reproductibilité du générateur
+    /// Deux générateurs avec la même graine donnent exactement les mêmes tirages.
+    #[test]
+    fn test_same_seed_gives_same_draws() {
+        let mut first = GameRng::new(42);
+        let mut second = GameRng::new(42);
+        let mut first_items: Vec<u32> = (0..20).collect();
+        let mut second_items = first_items.clone();
+
+        first.shuffle(&mut first_items);
+        second.shuffle(&mut second_items);
+
+        assert_eq!(first_items, second_items);
+        assert_eq!(first.choose(&first_items), second.choose(&second_items));
+        assert_eq!(first.chance(0.5), second.chance(0.5));
+        assert_eq!(first.seed(), 42);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::models::badge::BadgeEffect;
+use crate::models::ingredient::Ingredient;
+use crate::models::inventory::MAX_STACK_SIZE;
+use crate::services::engine::event::GameEvent;
+use crate::services::engine::game_engine::GameEngine;
+use crate::services::engine::state::GameScreenType;
+
+// Boutique des marchands, où le joueur achète ses ingrédients
+impl GameEngine {
+    /// Prix d'un ingrédient chez les marchands, après les réductions des badges du joueur.
+    /// Le prix réduit est arrondi et ne descend jamais sous 1.
+    pub fn trader_price(&self, ingredient: &Ingredient) -> i32 {
+        let discount: u32 = self
+            .badge_effects()
+            .into_iter()
+            .filter_map(|effect| match effect {
+                BadgeEffect::TraderDiscount { percent } => Some(*percent),
+                _ => None,
+            })
+            .sum();
+        let percent = 100 - discount.min(100) as i32;
+        ((ingredient.price * percent + 50) / 100).max(1)
+    }
+
+    // Choisit le marchand dont les ingrédients sont proposés
+    pub(super) fn choose_trader(&mut self, trader_index: usize, events: &mut Vec<GameEvent>) {
+        if let Some(trader) = self.traders.get(trader_index) {
+            self.selected_trader = Some(trader_index);
+            self.shop_message = None;
+            events.push(GameEvent::TraderChosen(trader.pnj.caracter.name.clone()));
+        }
+    }
+
+    // Achète un ingrédient du marchand choisi avec le bankroll du joueur
+    pub(super) fn buy_ingredient(&mut self, ingredient_index: usize, events: &mut Vec<GameEvent>) {
+        if self.current_screen != GameScreenType::Shop {
+            return;
+        }
+        let Some(ingredient) = self
+            .selected_trader
+            .and_then(|index| self.traders.get(index))
+            .and_then(|trader| trader.ingredients.get(ingredient_index))
+            .cloned()
+        else {
+            return;
+        };
+        let price = self.trader_price(&ingredient);
+        let Some(player) = self.player.as_mut() else {
+            self.shop_message = Some("Aucun personnage pour payer.".to_string());
+            events.push(GameEvent::PurchaseRejected(ingredient.name));
+            return;
+        };
+        if player.ingredient_quantity(&ingredient.name) >= MAX_STACK_SIZE {
+            self.shop_message = Some(format!(
+                "Vous ne pouvez pas porter plus de {} {}.",
+                MAX_STACK_SIZE, ingredient.name
+            ));
+            events.push(GameEvent::PurchaseRejected(ingredient.name));
+            return;
+        }
+        if player.caracter.bankroll < price {
+            self.shop_message = Some(format!(
+                "Pas assez d'argent pour {} ({} pieces).",
+                ingredient.name, price
+            ));
+            events.push(GameEvent::PurchaseRejected(ingredient.name));
+            return;
+        }
+
+        player.caracter.bankroll -= price;
+        player.add_ingredient(&ingredient.name, 1);
+        self.shop_message = Some(format!(
+            "{} achete pour {} pieces.",
+            ingredient.name, price
+        ));
+        events.push(GameEvent::IngredientBought {
+            ingredient: ingredient.name.clone(),
+            bankroll: player.caracter.bankroll,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::badge::{Badge, BadgeEffect};
+    use crate::services::engine::command::GameCommand;
+    use crate::services::engine::event::GameEvent;
+    use crate::services::engine::test_support::*;
+
+    /// Test d'un achat chez un marchand
+    /// L'ingrédient est payé avec le bankroll et rejoint l'inventaire du joueur,
+    /// puis l'achat est refusé quand la bourse est vide.
+    #[test]
+    fn test_buying_ingredients_spends_bankroll() {
+        let mut engine = test_engine();
+        let mut player = test_player();
+        player.caracter.bankroll = 1;
+        engine.set_player(player);
+
+        engine.apply(GameCommand::OpenShop);
+        assert!(engine.apply(GameCommand::BuyIngredient(0)).is_empty());
+        let events = engine.apply(GameCommand::ChooseTrader(0));
+        assert_eq!(events, vec![GameEvent::TraderChosen("Marius".to_string())]);
+
+        let events = engine.apply(GameCommand::BuyIngredient(0));
+        assert_eq!(
+            events,
+            vec![GameEvent::IngredientBought { ingredient: "Pastis".to_string(), bankroll: 0 }]
+        );
+        let events = engine.apply(GameCommand::BuyIngredient(1));
+        assert_eq!(events, vec![GameEvent::PurchaseRejected("Eau".to_string())]);
+
+        assert_eq!(engine.player.as_ref().unwrap().caracter.bankroll, 0);
+        assert_eq!(engine.inventory_count("Pastis"), Some(3));
+        assert_eq!(engine.inventory_count("Eau"), Some(1));
+    }
+
+    /// Test des prix réduits par les badges
+    /// Le prix réduit est arrondi, et un ingrédient à 1 reste payant.
+    #[test]
+    fn test_discounted_price_is_rounded_and_at_least_one() {
+        let mut engine = test_engine();
+        let mut player = test_player();
+        player.badges_won.push(Badge {
+            name: "Badge Test".to_string(),
+            features: vec![],
+            effects: vec![BadgeEffect::TraderDiscount { percent: 50 }],
+        });
+        engine.set_player(player);
+        let mut ingredient = ingredient("Pastis");
+
+        assert_eq!(engine.trader_price(&ingredient), 1);
+        ingredient.price = 5;
+        assert_eq!(engine.trader_price(&ingredient), 3);
+        ingredient.price = 10;
+        assert_eq!(engine.trader_price(&ingredient), 5);
+    }
+
+    /// Test d'un achat sans personnage chargé
+    #[test]
+    fn test_buying_without_player_is_rejected() {
+        let mut engine = test_engine();
+        engine.apply(GameCommand::OpenShop);
+        engine.apply(GameCommand::ChooseTrader(0));
+
+        let events = engine.apply(GameCommand::BuyIngredient(0));
+
+        assert_eq!(events, vec![GameEvent::PurchaseRejected("Pastis".to_string())]);
+        assert!(engine.shop_message.is_some());
+    }
+}
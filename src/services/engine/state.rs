@@ -0,0 +1,71 @@
+use crate::models::attack::StatusEffect;
+
+/// Types d'écrans du jeu
+#[derive(Default, PartialEq, Debug, Clone, Copy)]
+pub enum GameScreenType {
+    #[default]
+    Main,
+    ArenaSelection,
+    ArenaPresentation,
+    BouncerQuestion,
+    Arena,
+    InstructionOrdering,
+    ArenaEnd,
+    Shop,
+    BarService,
+    BadgeCase,
+}
+
+/// État du combat dans l'Arene
+#[derive(Default, PartialEq, Debug, Clone, Copy)]
+pub enum ArenaCombatState {
+    #[default]
+    Start,
+    PlayerTurn,
+    BossTurn,
+    Victory,
+    Defeat,
+}
+
+/// État de l'artisanat de cocktail en cours
+#[derive(Default, Debug, Clone)]
+pub struct CurrentCocktailCrafting {
+    pub selected_ingredients: Vec<String>,
+    /// Index dans `instruction_order` des instructions choisies, dans l'ordre du joueur
+    pub selected_steps: Vec<usize>,
+    pub instruction_correct: bool,
+    /// Nombre d'étapes justes au début de la dernière séquence validée
+    pub correct_steps: Option<usize>,
+    pub cocktail_ready: bool,
+}
+
+/// Résultat de la dernière attaque du boss, affiché au joueur
+#[derive(Debug, Clone, PartialEq)]
+pub struct BossAttackReport {
+    pub attack: String,
+    pub hit: bool,
+    pub blocked: bool,
+    pub damage: i32,
+    pub status_effect: Option<StatusEffect>,
+}
+
+impl BossAttackReport {
+    /// Message décrivant ce qui a touché le joueur
+    pub fn describe(&self, master_name: &str) -> String {
+        if !self.hit {
+            return format!("{} utilise {}... mais rate son attaque !", master_name, self.attack);
+        }
+        if self.blocked {
+            return format!("{} utilise {}... bloque par votre bouclier !", master_name, self.attack);
+        }
+
+        let mut message = format!(
+            "{} utilise {} : vous perdez {} HP !",
+            master_name, self.attack, self.damage
+        );
+        if let Some(effect) = &self.status_effect {
+            message.push_str(&format!("\nVous etes {}", effect.label()));
+        }
+        message
+    }
+}
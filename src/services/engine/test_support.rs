@@ -0,0 +1,225 @@
+use crate::models::aptitude::{Aptitude, AptitudeEffect};
+use crate::models::arena::Arena;
+use crate::models::attack::{Attack, StatusEffect};
+use crate::models::badge::Badge;
+use crate::models::caracter::bouncer::{AnswerMode, Bouncer};
+use crate::models::caracter::caracter::Caracter;
+use crate::models::caracter::client::Client;
+use crate::models::caracter::master::Master;
+use crate::models::caracter::player::Player;
+use crate::models::caracter::pnj::Pnj;
+use crate::models::caracter::trader::Trader;
+use crate::models::enigma::Enigma;
+use crate::models::ingredient::Ingredient;
+use crate::models::inventory::InventoryStack;
+use crate::models::level::LevelStep;
+use crate::models::recipe::{Recipe, RecipeItem};
+use crate::services::engine::command::GameCommand;
+use crate::services::engine::event::GameEvent;
+use crate::services::engine::game_engine::GameEngine;
+
+pub fn pnj(name: &str, hp: i32) -> Pnj {
+    Pnj {
+        caracter: Caracter::new(name, "Test", hp, 50, 0),
+        job: "Test".to_string(),
+        dialogs: vec!["Bonjour".to_string()],
+    }
+}
+
+pub fn ingredient(name: &str) -> Ingredient {
+    Ingredient {
+        name: name.to_string(),
+        description: String::new(),
+        price: 1,
+        type_ing: "Alcool".to_string(),
+    }
+}
+
+pub fn stack(name: &str, quantity: u32) -> InventoryStack {
+    InventoryStack {
+        ingredient_id: name.to_string(),
+        quantity,
+    }
+}
+
+pub fn recipe_item(name: &str, quantity: u32, unit: &str) -> RecipeItem {
+    RecipeItem {
+        ingredient: name.to_string(),
+        quantity,
+        unit: unit.to_string(),
+    }
+}
+
+pub fn attack(damage: i32, accuracy: f32, status_effect: Option<StatusEffect>) -> Attack {
+    Attack {
+        name: "Jet d'Anis".to_string(),
+        damage,
+        accuracy,
+        status_effect,
+    }
+}
+
+pub fn aptitude(name: &str, pp: i32, power: f32, effect: AptitudeEffect) -> Aptitude {
+    Aptitude {
+        name: name.to_string(),
+        description: String::new(),
+        pp,
+        power,
+        effect: Some(effect),
+    }
+}
+
+pub fn arena(id: &str, master_id: &str, difficulty: u32, unlock_requirements: &[&str]) -> Arena {
+    Arena {
+        id: id.to_string(),
+        name: id.to_uppercase(),
+        theme: "Test".to_string(),
+        master_id: master_id.to_string(),
+        bouncer_id: "ragnar".to_string(),
+        difficulty,
+        unlock_requirements: unlock_requirements.iter().map(|id| id.to_string()).collect(),
+        required_badges: vec![],
+        hidden: false,
+        championship: false,
+    }
+}
+
+pub fn master(id: &str, name: &str, hp: i32, boss_attack: Attack, badge: &str) -> Master {
+    Master {
+        id: id.to_string(),
+        pnj: pnj(name, hp),
+        badge: Badge {
+            name: badge.to_string(),
+            features: vec![],
+            effects: vec![],
+        },
+        attacks: vec![boss_attack],
+        recipe: Recipe {
+            ingredients: vec![recipe_item("Pastis", 2, "cl"), recipe_item("Eau", 1, "")],
+            instructions: vec![
+                "Verser le Pastis".to_string(),
+                "Ajouter l'eau".to_string(),
+            ],
+        },
+    }
+}
+
+/// Moteur de test avec l'arène PASTIS, son maître (120 HP, une seule attaque) et un videur.
+/// L'arène CIDRE, plus difficile, n'est ouverte qu'après PASTIS, et son maître
+/// est volontairement placé en premier dans la liste des maîtres.
+pub fn test_engine_with_attack(boss_attack: Attack) -> GameEngine {
+    let arenas = vec![
+        arena("cidre", "cidre_lord", 3, &["pastis"]),
+        arena("pastis", "pastis_man", 1, &[]),
+    ];
+    let masters = vec![
+        master("cidre_lord", "Cidre Lord", 200, boss_attack.clone(), "Cidre Badge"),
+        master("pastis_man", "Pastis Man", 120, boss_attack, "Pastis Badge"),
+    ];
+    let bouncers = vec![Bouncer {
+        id: "ragnar".to_string(),
+        pnj: pnj("Ragnar", 100),
+        enigmas: vec![Enigma {
+            question: "Qui suis-je ?".to_string(),
+            answer: "L'homme".to_string(),
+            distractors: vec!["Un animal".to_string(), "Le temps".to_string()],
+            hint: Some("Il marche avec une canne le soir".to_string()),
+            accepted_answers: vec!["L'être humain".to_string()],
+        }],
+        answer_mode: AnswerMode::Choices,
+    }];
+    let traders = vec![Trader {
+        pnj: pnj("Marius", 80),
+        ingredients: vec![ingredient("Pastis"), ingredient("Eau")],
+    }];
+    let clients = vec![Client {
+        pnj: pnj("Hugo", 100),
+        tips: 5,
+        advices: vec!["Toujours servir frais".to_string()],
+        drink: "Pastis bien frais".to_string(),
+        order: Recipe {
+            ingredients: vec![recipe_item("Pastis", 1, "dose"), recipe_item("Eau", 1, "")],
+            instructions: vec!["Verser le Pastis".to_string(), "Ajouter l'eau".to_string()],
+        },
+    }];
+    GameEngine::new(arenas, masters, bouncers, traders, clients)
+}
+
+/// Moteur de test dont le boss touche toujours pour 5 HP
+pub fn test_engine() -> GameEngine {
+    test_engine_with_attack(attack(5, 1.0, None))
+}
+
+/// Joueur de test avec 80 HP, 30 PP et les ingrédients du cocktail du maître (plus une vodka)
+pub fn test_player() -> Player {
+    let mut player = Player::new(
+        "Elendil",
+        "Elfe",
+        Badge {
+            name: "Badge 1".to_string(),
+            features: vec![],
+            effects: vec![],
+        },
+        vec![stack("Pastis", 2), stack("Eau", 1), stack("Vodka", 1)],
+        vec![aptitude("Mixologie", 10, 2.0, AptitudeEffect::DamageBoost)],
+    );
+    player.max_hp = 80;
+    player.max_pp = 30;
+    player.caracter.hp = 80;
+    player.caracter.pp = 30;
+    player
+}
+
+/// Courbe de test : le niveau 2 est atteint à 40 XP et libère un second emplacement
+pub fn test_level_curve() -> Vec<LevelStep> {
+    vec![
+        LevelStep { level: 1, xp: 0, max_hp: 100, max_pp: 50, aptitude_slots: 1 },
+        LevelStep { level: 2, xp: 40, max_hp: 120, max_pp: 60, aptitude_slots: 2 },
+    ]
+}
+
+/// Donne la bonne réponse au videur
+pub fn answer_bouncer_correctly(engine: &mut GameEngine) -> Vec<GameEvent> {
+    let correct_index = engine
+        .answer_options
+        .iter()
+        .position(|option| *option == engine.correct_answer)
+        .unwrap();
+    engine.apply(GameCommand::AnswerBouncer(correct_index))
+}
+
+/// Amène le moteur jusqu'à l'écran de combat (après l'introduction)
+pub fn enter_combat(engine: &mut GameEngine) {
+    engine.apply(GameCommand::OpenArenaSelection);
+    engine.apply(GameCommand::ChooseArena(0));
+    answer_bouncer_correctly(engine);
+    engine.apply(GameCommand::EnterArena);
+    engine.apply(GameCommand::StartArenaCombat);
+}
+
+/// Sélectionne et valide le bon cocktail
+pub fn craft_correct_cocktail(engine: &mut GameEngine) -> Vec<GameEvent> {
+    engine.apply(GameCommand::ToggleIngredient("Pastis".to_string()));
+    engine.apply(GameCommand::ToggleIngredient("Eau".to_string()));
+    engine.apply(GameCommand::ValidateCocktail)
+}
+
+/// Choisit la première instruction encore disponible qui porte ce texte
+pub fn select_instruction(engine: &mut GameEngine, instruction: &str) -> Vec<GameEvent> {
+    let (index, _) = engine
+        .remaining_instructions()
+        .into_iter()
+        .find(|(_, remaining)| *remaining == instruction)
+        .expect("instruction disponible");
+    engine.apply(GameCommand::SelectInstruction(index))
+}
+
+/// Joue une arène complète jusqu'à la victoire
+pub fn win_arena(engine: &mut GameEngine) -> Vec<GameEvent> {
+    enter_combat(engine);
+    craft_correct_cocktail(engine);
+    engine.apply(GameCommand::StartFinalCraft);
+    select_instruction(engine, "Verser le Pastis");
+    select_instruction(engine, "Ajouter l'eau");
+    engine.apply(GameCommand::ValidateInstructionOrder)
+}
@@ -0,0 +1,185 @@
+use crate::services::content::GameContent;
+use crate::services::json_loader::LoadError;
+use crate::services::replay::{Replay, ReplayPlayer};
+use crate::services::tui::app::{TuiApp, TuiInput, TuiKey};
+use crate::services::tui::view::TuiView;
+use std::io::{self, BufRead, Write};
+
+/// Joue la partie sans affichage interactif, avec les écrans de l'interface terminal.
+/// Chaque écran est écrit en texte, puis une ligne est lue :
+/// - un numéro active le choix correspondant ;
+/// - une ligne vide valide le choix courant ou termine la saisie ;
+/// - tout autre texte est tapé dans le champ de saisie de l'écran.
+///
+/// La partie est sauvegardée et s'arrête à la fin de l'entrée.
+///
+/// # Arguments
+/// * `app` - L'interface terminal à piloter.
+/// * `input` - Les lignes de commandes du joueur, par exemple l'entrée standard.
+/// * `output` - La sortie où les écrans sont écrits.
+pub fn run(app: &mut TuiApp, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    let mut lines = input.lines();
+
+    while app.is_running() {
+        let view = app.view();
+        write!(output, "{}", render(&view))?;
+        output.flush()?;
+
+        let Some(line) = lines.next().transpose()? else {
+            app.handle_input(TuiInput::Quit);
+            break;
+        };
+        let line = line.trim();
+
+        if line.is_empty() {
+            app.handle_input(TuiInput::Key(TuiKey::Enter));
+        } else if view.input.is_some() {
+            for c in line.chars() {
+                app.handle_input(TuiInput::Key(TuiKey::Char(c)));
+            }
+        } else if !line
+            .parse()
+            .is_ok_and(|number| app.handle_input(TuiInput::Choose(number)))
+        {
+            writeln!(output, "Choix invalide : {}", line)?;
+        }
+    }
+    Ok(())
+}
+
+/// Rejoue sans affichage une session enregistrée avec l'interface terminal, aussi vite que possible.
+/// Le jeu repart de la graine, des slots et des options de l'enregistrement, puis chaque entrée
+/// du joueur est renvoyée à l'interface : chaque écran est écrit avec l'entrée qui le quitte.
+/// Les sauvegardes sont faites dans une copie temporaire des slots : ceux du joueur ne changent pas.
+///
+/// # Arguments
+/// * `content` - Le contenu du jeu, le même que pendant l'enregistrement.
+/// * `load_errors` - Les erreurs de chargement du contenu, affichées avant le menu comme en jeu.
+/// * `replay` - La session enregistrée.
+/// * `output` - La sortie où le déroulé est écrit.
+pub fn replay(
+    content: GameContent,
+    load_errors: Vec<LoadError>,
+    replay: Replay<TuiInput>,
+    mut output: impl Write,
+) -> io::Result<TuiApp> {
+    let player = ReplayPlayer::new(replay)?;
+    let header = &player.replay.header;
+    let mut app = TuiApp::new(content, load_errors, player.save_dir(), Some(header.seed));
+    app.start_with(header.slot, header.arena.as_deref());
+    writeln!(output, "Graine : {}", header.seed)?;
+
+    for input in &player.replay.inputs {
+        write!(output, "{}", render(&app.view()))?;
+        writeln!(output, "[{} ms] {:?}", input.time_ms, input.input)?;
+        app.handle_input(input.input.clone());
+    }
+    if app.is_running() {
+        write!(output, "{}", render(&app.view()))?;
+    }
+    writeln!(output, "Fin du rejeu")?;
+    Ok(app)
+}
+
+// Écrit un écran en texte : le titre, les lignes, la saisie puis les choix numérotés
+fn render(view: &TuiView) -> String {
+    let mut text = format!("== {} ==\n", view.title);
+    for line in &view.lines {
+        text.push_str(&format!("{}\n", line));
+    }
+    if let Some(input) = &view.input {
+        text.push_str(&format!("Saisie : {}_\n", input));
+    }
+    for (index, choice) in view.choices.iter().enumerate() {
+        text.push_str(&format!("{}. {}\n", index + 1, choice.label));
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::save_game::SaveGame;
+    use crate::services::json_loader::JsonLoader;
+    use crate::services::session::{
+        aptitude_picks, create_player, PlayerCreationData, SaveDirectory,
+    };
+    use std::fs;
+    use std::io::Cursor;
+    use tempfile::tempdir;
+
+    /// Test d'une partie sans affichage, de la création du personnage à l'écran de jeu
+    /// Le joueur est créé dans le dossier de sauvegarde choisi, puis sauvegardé à la fin de l'entrée.
+    #[test]
+    fn test_headless_creates_player_and_plays() {
+        let dir = tempdir().unwrap();
+        let save_dir = SaveDirectory(dir.path().to_str().unwrap().to_string());
+        let content = GameContent::load("assets").unwrap();
+
+        // Nom, style, badges puis aptitudes : le premier badge et les premières aptitudes
+        let first_badge = 3;
+        let first_aptitude = first_badge + content.badges.len();
+        let mut commands = vec!["1", "1", "1", "Elendil", "", "3"]
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+        for i in 0..aptitude_picks(&content) {
+            commands.push((first_aptitude + i).to_string());
+        }
+        commands.push((first_aptitude + content.aptitudes.len()).to_string());
+        commands.push("1".to_string());
+        let input = Cursor::new(commands.join("\n"));
+
+        let mut app = TuiApp::new(content, vec![], save_dir.clone(), None);
+        let mut output = Vec::new();
+        run(&mut app, input, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("== Choisissez votre Arene =="));
+        assert!(!output.contains("Choix invalide"));
+        assert!(!app.is_running());
+        let save = JsonLoader::load_save_game(&save_dir.slot_path(0)).unwrap();
+        assert_eq!(save.player.caracter.name, "Elendil");
+    }
+
+    /// Test d'une session du terminal enregistrée puis rejouée
+    /// Le rejeu repart de la graine et des slots enregistrés, retrouve exactement la même partie
+    /// et ne modifie pas les sauvegardes du joueur.
+    #[test]
+    fn test_recorded_session_replays_identically() {
+        let dir = tempdir().unwrap();
+        let save_dir = SaveDirectory(dir.path().to_str().unwrap().to_string());
+        let path = dir.path().join("session.jsonl").to_str().unwrap().to_string();
+        let content = GameContent::load("assets").unwrap();
+        let arena_id = content.arenas[0].id.clone();
+        let creation_data = PlayerCreationData {
+            name: "Elendil".to_string(),
+            selected_badge_index: Some(0),
+            selected_aptitudes: (0..aptitude_picks(&content)).collect(),
+            ..Default::default()
+        };
+        let player = create_player(&creation_data, &content).unwrap();
+        JsonLoader::save_game(&save_dir.slot_path(0), &SaveGame::new(player)).unwrap();
+
+        let mut app = TuiApp::new(content, vec![], save_dir.clone(), None);
+        app.record_replay(path.clone(), Some(0), Some(arena_id.clone()));
+        app.start_with(Some(0), Some(&arena_id));
+        run(&mut app, Cursor::new("1\n1\n2\n1\n"), &mut Vec::new()).unwrap();
+        let saved = fs::read_to_string(save_dir.slot_path(0)).unwrap();
+
+        let recorded = JsonLoader::load_replay::<TuiInput>(&path).unwrap();
+        assert_eq!(recorded.header.slot, Some(0));
+        assert_eq!(recorded.inputs.last().unwrap().input, TuiInput::Quit);
+        let mut output = Vec::new();
+        let content = GameContent::load("assets").unwrap();
+        let replayed = replay(content, vec![], recorded, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("Fin du rejeu"));
+        assert_eq!(replayed.engine.current_screen, app.engine.current_screen);
+        assert_eq!(replayed.engine.player_hp, app.engine.player_hp);
+        assert_eq!(replayed.engine.boss_hp, app.engine.boss_hp);
+        assert_eq!(replayed.engine.current_boss_attack, app.engine.current_boss_attack);
+        assert_eq!(fs::read_to_string(save_dir.slot_path(0)).unwrap(), saved);
+    }
+}
@@ -1,112 +1,390 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use serde_json;
+use std::fmt;
+use std::fs;
 use std::fs::File;
+use std::io;
 use std::io::Read;
 use std::io::Write;
+use std::path::Path;
+
+use crate::models::save_game::SaveGame;
+use crate::services::replay::{RecordedInput, Replay, ReplayHeader};
+use crate::services::save_migration::migrate_to_current;
+
+/// Nombre de versions précédentes conservées pour chaque slot de sauvegarde
+pub const SAVE_BACKUP_COUNT: usize = 3;
+
+/// Erreur de chargement d'un fichier de données du jeu
+/// Chaque variante garde le chemin du fichier pour pouvoir l'afficher au joueur.
+#[derive(Debug)]
+pub enum LoadError {
+    /// Le fichier n'existe pas
+    NotFound { path: String },
+    /// Le fichier existe mais n'a pas pu être lu
+    Io { path: String, source: io::Error },
+    /// Le contenu n'est pas un JSON valide ou ne correspond pas au modèle attendu
+    Json {
+        path: String,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+}
+
+impl LoadError {
+    /// Convertit une erreur d'E/S en `LoadError` pour le fichier donné
+    pub fn from_io(path: &str, source: io::Error) -> Self {
+        let path = path.to_string();
+        if source.kind() == io::ErrorKind::NotFound {
+            LoadError::NotFound { path }
+        } else {
+            LoadError::Io { path, source }
+        }
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::NotFound { path } => write!(f, "{} : fichier introuvable", path),
+            LoadError::Io { path, source } => write!(f, "{} : erreur de lecture ({})", path, source),
+            LoadError::Json { path, line, column, message } => {
+                write!(f, "{} (ligne {}, colonne {}) : {}", path, line, column, message)
+            }
+        }
+    }
+}
 
-use crate::models::aptitude::Aptitude;
-use crate::models::arena::Arena;
-use crate::models::caracter::bouncer::Bouncer;
-use crate::models::caracter::master::Master;
-use crate::models::caracter::player::Player;
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Erreur de lecture ou d'écriture d'une sauvegarde
+/// Les appelants peuvent distinguer un slot vide, une sauvegarde illisible ou trop récente
+/// et un échec d'écriture sur le disque.
+#[derive(Debug)]
+pub enum SaveError {
+    /// Le fichier de sauvegarde est absent, illisible ou n'est pas un JSON valide
+    Load(LoadError),
+    /// La sauvegarde vient d'une version du jeu non supportée ou sa migration a échoué
+    Migration { path: String, message: String },
+    /// Le contenu ne correspond pas au modèle de sauvegarde
+    Invalid { path: String, message: String },
+    /// La sauvegarde ou ses sauvegardes de secours n'ont pas pu être écrites
+    Write { path: String, source: io::Error },
+    /// Aucune sauvegarde de secours valide n'existe pour ce slot
+    NoValidBackup { path: String },
+}
+
+impl SaveError {
+    // Construit l'erreur d'écriture d'un fichier de sauvegarde
+    fn write(path: &str, source: io::Error) -> Self {
+        SaveError::Write {
+            path: path.to_string(),
+            source,
+        }
+    }
+}
+
+impl From<LoadError> for SaveError {
+    fn from(error: LoadError) -> Self {
+        SaveError::Load(error)
+    }
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Load(error) => write!(f, "{}", error),
+            SaveError::Migration { path, message } => write!(f, "{} : {}", path, message),
+            SaveError::Invalid { path, message } => {
+                write!(f, "{} : sauvegarde invalide ({})", path, message)
+            }
+            SaveError::Write { path, source } => write!(f, "{} : erreur d'ecriture ({})", path, source),
+            SaveError::NoValidBackup { path } => {
+                write!(f, "Aucune sauvegarde de secours valide pour {}", path)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SaveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SaveError::Load(error) => Some(error),
+            SaveError::Write { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
 
 /// Chargeur de données JSON pour le jeu
 /// Ce module fournit des fonctions pour charger des données de jeu à partir de fichiers JSON,
 pub struct JsonLoader {}
 
 impl JsonLoader {
-    /// Charge une liste de maîtres depuis le fichier JSON
-    /// Cette fonction lit un fichier JSON contenant des maîtres et les charge dans une liste.
-    /// Elle retourne un `Result` qui contient soit la liste de maîtres, soit une erreur.
+    /// Charge un fichier JSON et le convertit dans le type demandé
+    /// Cette fonction sert pour tous les fichiers de données (maîtres, arènes, videurs, aptitudes...).
+    /// Elle retourne un `Result` qui contient soit les données, soit une `LoadError`.
     /// # Arguments
     /// * `file_path` - Le chemin du fichier JSON à charger.
-    pub fn load_json_masters(file_path: &str) -> Result<Vec<Master>, Box<dyn std::error::Error>> {
-        let mut file = File::open(file_path)?;
-        let mut data = String::new();
-        file.read_to_string(&mut data)?;
+    ///
+    /// # Exemple
+    /// ```
+    /// let masters: Vec<Master> = JsonLoader::load_json("assets/caracters/pnj/masters.json")?;
+    /// ```
+    pub fn load_json<T: DeserializeOwned>(file_path: &str) -> Result<T, LoadError> {
+        let data = Self::read_file(file_path)?;
+        Self::parse_json(file_path, &data)
+    }
 
-        let masters: Vec<Master> = serde_json::from_str(&data)?;
-        Ok(masters)
+    /// Sauvegarde une partie dans un fichier JSON au format actuel
+    /// Cette fonction écrit la sauvegarde complète (joueur, progression, réglages)
+    /// avec sa `format_version`. L'écriture est atomique : on écrit d'abord un fichier
+    /// temporaire et on le synchronise sur le disque, puis on décale les `.bak`, on renomme
+    /// le fichier temporaire à la place du fichier du slot et on synchronise le dossier.
+    /// Un crash ou un disque plein pendant l'écriture laisse donc l'ancienne sauvegarde
+    /// et ses `SAVE_BACKUP_COUNT` versions précédentes intactes.
+    /// # Arguments
+    /// * `file_path` - Le chemin du fichier JSON où sauvegarder la partie.
+    /// * `save` - La sauvegarde à écrire.
+    pub fn save_game(file_path: &str, save: &SaveGame) -> Result<(), SaveError> {
+        let json = Self::to_json(file_path, save)?;
+        Self::write_atomically(file_path, json.as_bytes()).map_err(|e| SaveError::write(file_path, e))
     }
 
-    /// Charge une liste d'arènes depuis le fichier JSON
-    /// Cette fonction lit un fichier JSON contenant des arènes et les charge dans une liste.
-    /// Elle retourne un `Result` qui contient soit la liste d'arènes, soit une erreur.
+    /// Charge une sauvegarde depuis un fichier JSON
+    /// Cette fonction lit la sauvegarde, applique les migrations nécessaires si le fichier
+    /// a été écrit par une ancienne version du jeu, puis la convertit en `SaveGame`.
     /// # Arguments
     /// * `file_path` - Le chemin du fichier JSON à charger.
-    pub fn load_json_arena(file_path: &str) -> Result<Vec<Arena>, Box<dyn std::error::Error>> {
-        let mut file = File::open(file_path)?;
-        let mut data = String::new();
-        file.read_to_string(&mut data)?;
+    pub fn load_save_game(file_path: &str) -> Result<SaveGame, SaveError> {
+        let data = Self::read_file(file_path)?;
+
+        let raw: serde_json::Value = Self::parse_json(file_path, &data)?;
+        let migrated = migrate_to_current(raw).map_err(|message| SaveError::Migration {
+            path: file_path.to_string(),
+            message,
+        })?;
+        let save: SaveGame = serde_json::from_value(migrated).map_err(|e| SaveError::Invalid {
+            path: file_path.to_string(),
+            message: e.to_string(),
+        })?;
+        Ok(save)
+    }
 
-        let arena: Vec<Arena> = serde_json::from_str(&data)?;
-        Ok(arena)
+    // Convertit une sauvegarde ou un rejeu en JSON lisible avant de l'écrire
+    fn to_json<T: Serialize>(file_path: &str, value: &T) -> Result<String, SaveError> {
+        serde_json::to_string_pretty(value).map_err(|e| SaveError::Invalid {
+            path: file_path.to_string(),
+            message: e.to_string(),
+        })
     }
 
-    /// Charge une liste de videurs depuis le fichier JSON
-    /// Cette fonction lit un fichier JSON contenant des videurs et les charge dans une liste.
-    /// Elle retourne un `Result` qui contient soit la liste de videurs, soit une erreur.
-    /// # Arguments
-    /// * `file_path` - Le chemin du fichier JSON à charger.
-    pub fn load_json_bouncers(file_path: &str) -> Result<Vec<Bouncer>, Box<dyn std::error::Error>> {
-        let mut file = File::open(file_path)?;
+    // Lit le contenu d'un fichier en distinguant un fichier absent d'une autre erreur d'E/S
+    fn read_file(file_path: &str) -> Result<String, LoadError> {
+        let mut file = File::open(file_path).map_err(|e| LoadError::from_io(file_path, e))?;
         let mut data = String::new();
-        file.read_to_string(&mut data)?;
+        file.read_to_string(&mut data)
+            .map_err(|e| LoadError::from_io(file_path, e))?;
+        Ok(data)
+    }
 
-        let bouncers: Vec<Bouncer> = serde_json::from_str(&data)?;
-        Ok(bouncers)
+    /// Charge une session de jeu enregistrée, au format JSON Lines :
+    /// l'en-tête sur la première ligne puis une entrée du joueur par ligne.
+    /// Une dernière ligne coupée par un arrêt brutal du jeu est ignorée,
+    /// et une session enregistrée par une autre interface est refusée.
+    /// # Arguments
+    /// * `file_path` - Le chemin du fichier de rejeu.
+    pub fn load_replay<I: RecordedInput>(file_path: &str) -> Result<Replay<I>, LoadError> {
+        let data = Self::read_file(file_path)?;
+        let complete = data.ends_with('\n');
+        let lines: Vec<_> = data
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .collect();
+        let Some((&(_, header_line), input_lines)) = lines.split_first() else {
+            return Err(Self::replay_error(file_path, 1, "fichier de rejeu vide"));
+        };
+
+        let header: ReplayHeader = Self::parse_json_line(file_path, 0, header_line)?;
+        if header.frontend != I::FRONTEND {
+            return Err(Self::replay_error(
+                file_path,
+                1,
+                &format!("session enregistree avec l'interface {}", header.frontend),
+            ));
+        }
+
+        let mut inputs = Vec::with_capacity(input_lines.len());
+        for (position, &(index, line)) in input_lines.iter().enumerate() {
+            match Self::parse_json_line(file_path, index, line) {
+                Ok(input) => inputs.push(input),
+                Err(_) if !complete && position + 1 == input_lines.len() => break,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(Replay { header, inputs })
     }
-    // Charge une liste d'aptitudes depuis le fichier JSON
 
-    pub fn load_json_aptitudes(
+    // Désérialise une ligne d'un fichier JSON Lines, avec son numéro de ligne dans le fichier
+    fn parse_json_line<T: DeserializeOwned>(
         file_path: &str,
-    ) -> Result<Vec<Aptitude>, Box<dyn std::error::Error>> {
-        let mut file = File::open(file_path)?;
-        let mut data = String::new();
-        file.read_to_string(&mut data)?;
+        index: usize,
+        line: &str,
+    ) -> Result<T, LoadError> {
+        Self::parse_json(file_path, line).map_err(|e| match e {
+            LoadError::Json { path, column, message, .. } => LoadError::Json {
+                path,
+                line: index + 1,
+                column,
+                message,
+            },
+            e => e,
+        })
+    }
+
+    // Erreur de contenu d'un fichier de rejeu, rattachée à une ligne
+    fn replay_error(file_path: &str, line: usize, message: &str) -> LoadError {
+        LoadError::Json {
+            path: file_path.to_string(),
+            line,
+            column: 1,
+            message: message.to_string(),
+        }
+    }
 
-        let aptitudes: Vec<Aptitude> = serde_json::from_str(&data)?;
-        Ok(aptitudes)
+    // Désérialise un contenu JSON en gardant la position de l'erreur dans le fichier
+    fn parse_json<T: DeserializeOwned>(file_path: &str, data: &str) -> Result<T, LoadError> {
+        serde_json::from_str(data).map_err(|e| LoadError::Json {
+            path: file_path.to_string(),
+            line: e.line(),
+            column: e.column(),
+            message: e.to_string(),
+        })
     }
 
-    /// Sauvegarde le joueur dans un fichier JSON organisé par niveau
-    /// Cette fonction lit un fichier JSON existant, ajoute ou met à jour le joueur avec son niveau comme clé,
-    /// et enregistre le tout dans le fichier.
+    /// Retourne le chemin de la n-ième sauvegarde de secours d'un slot
+    /// La sauvegarde `1` est la plus récente.
     /// # Arguments
-    /// * `file_path` - Le chemin du fichier JSON où sauvegarder le joueur.
-    /// * `player` - Le joueur à sauvegarder.
-    pub fn save_player_to_json(
-        file_path: &str,
-        player: &Player,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        let mut players: serde_json::Map<String, serde_json::Value> = serde_json::Map::new();
-
-        if let Ok(mut file) = File::open(file_path) {
-            let mut data = String::new();
-            file.read_to_string(&mut data)?;
-            if !data.trim().is_empty() {
-                players = serde_json::from_str(&data)?;
-            }
+    /// * `file_path` - Le chemin du fichier de sauvegarde du slot.
+    /// * `index` - Le numéro de la sauvegarde de secours (de 1 à `SAVE_BACKUP_COUNT`).
+    pub fn backup_path(file_path: &str, index: usize) -> String {
+        format!("{}.{}.bak", file_path, index)
+    }
+
+    /// Cherche la sauvegarde de secours valide la plus récente d'un slot
+    /// Retourne le numéro de la sauvegarde de secours si l'une d'elles peut être chargée.
+    /// # Arguments
+    /// * `file_path` - Le chemin du fichier de sauvegarde du slot.
+    pub fn latest_valid_backup(file_path: &str) -> Option<usize> {
+        (1..=SAVE_BACKUP_COUNT)
+            .find(|index| Self::load_save_game(&Self::backup_path(file_path, *index)).is_ok())
+    }
+
+    /// Restaure la sauvegarde de secours valide la plus récente à la place du fichier du slot
+    /// Cette fonction est utilisée quand le fichier principal ne peut plus être lu.
+    /// Retourne la sauvegarde restaurée.
+    /// # Arguments
+    /// * `file_path` - Le chemin du fichier de sauvegarde du slot.
+    pub fn restore_latest_backup(file_path: &str) -> Result<SaveGame, SaveError> {
+        let index = Self::latest_valid_backup(file_path).ok_or_else(|| SaveError::NoValidBackup {
+            path: file_path.to_string(),
+        })?;
+        let backup_path = Self::backup_path(file_path, index);
+        let backup = fs::read(&backup_path).map_err(|e| LoadError::from_io(&backup_path, e))?;
+
+        // On ne fait pas tourner les .bak : le fichier principal est illisible
+        Self::write_file_atomically(file_path, &backup).map_err(|e| SaveError::write(file_path, e))?;
+        Self::load_save_game(file_path)
+    }
+
+    // Écrit un fichier de sauvegarde de façon atomique en gardant les versions précédentes.
+    // Les sauvegardes de secours ne sont décalées qu'une fois les nouvelles données sur le
+    // disque : si l'écriture échoue, la plus ancienne n'est pas perdue pour rien.
+    fn write_atomically(file_path: &str, data: &[u8]) -> io::Result<()> {
+        let tmp_path = Self::write_temp_file(file_path, data)?;
+        if Path::new(file_path).exists() {
+            Self::rotate_backups(file_path)?;
         }
-        // Ajoute le nouveau joueur avec son niveau comme clé
+        Self::replace_with_temp_file(&tmp_path, file_path)
+    }
 
-        let player_level = player.level.to_string();
+    // Écrit dans un fichier temporaire synchronisé sur le disque puis le renomme
+    fn write_file_atomically(file_path: &str, data: &[u8]) -> io::Result<()> {
+        let tmp_path = Self::write_temp_file(file_path, data)?;
+        Self::replace_with_temp_file(&tmp_path, file_path)
+    }
+
+    // Écrit les données dans un fichier temporaire à côté du fichier cible et le synchronise
+    // sur le disque. Le fichier temporaire est supprimé si l'écriture échoue.
+    fn write_temp_file(file_path: &str, data: &[u8]) -> io::Result<String> {
+        let tmp_path = format!("{}.tmp", file_path);
+        let written = File::create(&tmp_path).and_then(|mut file| {
+            file.write_all(data)?;
+            file.sync_all()
+        });
+        if let Err(e) = written {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(e);
+        }
 
-        let player_json = serde_json::to_value(player)?;
-        players.insert(player_level, player_json);
+        Ok(tmp_path)
+    }
 
-        let json = serde_json::to_string_pretty(&players)?;
-        let mut file = File::create(file_path)?;
-        file.write_all(json.as_bytes())?;
+    // Renomme le fichier temporaire à la place du fichier cible puis synchronise le dossier,
+    // pour que le renommage lui-même survive à un crash
+    fn replace_with_temp_file(tmp_path: &str, file_path: &str) -> io::Result<()> {
+        fs::rename(tmp_path, file_path)?;
+        Self::sync_parent_directory(file_path)?;
+
+        Ok(())
+    }
+
+    // Synchronise sur le disque le dossier qui contient le fichier.
+    // Seuls les systèmes Unix permettent d'ouvrir un dossier pour le synchroniser.
+    fn sync_parent_directory(file_path: &str) -> io::Result<()> {
+        if cfg!(unix) {
+            let parent = Path::new(file_path)
+                .parent()
+                .filter(|parent| !parent.as_os_str().is_empty())
+                .unwrap_or(Path::new("."));
+            File::open(parent)?.sync_all()?;
+        }
+
+        Ok(())
+    }
+
+    // Décale les sauvegardes de secours (.1.bak -> .2.bak...) et copie le fichier actuel en .1.bak
+    // La plus ancienne est écrasée.
+    fn rotate_backups(file_path: &str) -> io::Result<()> {
+        for index in (1..SAVE_BACKUP_COUNT).rev() {
+            let from = Self::backup_path(file_path, index);
+            if Path::new(&from).exists() {
+                fs::rename(&from, Self::backup_path(file_path, index + 1))?;
+            }
+        }
+        fs::copy(file_path, Self::backup_path(file_path, 1))?;
 
         Ok(())
     }
 
     /// On crée le dossier de sauvegarde s'il n'existe pas
-    /// Cette fonction vérifie si le dossier "save" existe, et le crée si nécessaire.
+    /// Cette fonction vérifie si le dossier des sauvegardes existe, et le crée si nécessaire.
     /// Si la création échoue, elle affiche un message d'erreur.
-    pub fn ensure_save_directory() {
-        use std::fs;
-        if let Err(e) = fs::create_dir_all("save") {
+    ///
+    /// # Arguments
+    /// * `save_dir` - Le dossier des sauvegardes des slots.
+    pub fn ensure_save_directory(save_dir: &str) {
+        if let Err(e) = fs::create_dir_all(save_dir) {
             println!("Erreur lors de la creation du dossier de sauvegarde: {}", e);
         }
     }
@@ -116,12 +394,27 @@ impl JsonLoader {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::aptitude::Aptitude;
+    use crate::models::arena::Arena;
+    use crate::models::caracter::bouncer::Bouncer;
+    use crate::models::caracter::master::Master;
+    use crate::models::caracter::player::Player;
     use std::fs;
     use std::io::Write;
     use tempfile::tempdir;
 
+    fn test_player(name: &str) -> Player {
+        Player::new(
+            name,
+            "Hero",
+            crate::models::badge::Badge { name: "Test Badge".to_string(), features: vec![], effects: vec![] },
+            vec![], // inventory vide
+            vec![]  // aptitudes vides
+        )
+    }
+
     /// Test de chargement réussi d'une liste de maîtres depuis un fichier JSON
-    /// Ce test vérifie que la fonction load_json_masters peut correctement charger
+    /// Ce test vérifie que la fonction load_json peut correctement charger
     /// et désérialiser des données de maîtres depuis un fichier JSON valide.
     /// Il teste également que les données sont correctement mappées aux champs de la structure Master.
     #[test]
@@ -131,6 +424,7 @@ mod tests {
         let file_path = dir.path().join("test_masters.json");
         let test_data = r#"[
             {
+                "id": "test_master",
                 "pnj": {
                     "caracter": {
                         "name": "Test Master",
@@ -146,7 +440,13 @@ mod tests {
                     "name": "Test Badge",
                     "features": ["Test feature"]
                 },
-                "attacks": ["Test Attack"],
+                "attacks": [
+                    {
+                        "name": "Test Attack",
+                        "damage": 10,
+                        "accuracy": 0.8
+                    }
+                ],
                 "recipe": {
                     "ingredients": [],
                     "instructions": ["Test instruction"]
@@ -158,7 +458,7 @@ mod tests {
         file.write_all(test_data.as_bytes()).unwrap();
 
         // Act
-        let result = JsonLoader::load_json_masters(file_path.to_str().unwrap());
+        let result = JsonLoader::load_json::<Vec<Master>>(file_path.to_str().unwrap());
 
         // Assert
         assert!(result.is_ok());
@@ -166,24 +466,30 @@ mod tests {
         assert_eq!(masters.len(), 1);
         assert_eq!(masters[0].pnj.caracter.name, "Test Master");
         assert_eq!(masters[0].pnj.job, "Barman Test");
+        assert_eq!(masters[0].attacks[0].damage, 10);
+        assert!(masters[0].attacks[0].status_effect.is_none());
     }
 
     /// Test de gestion d'erreur lors du chargement d'un fichier inexistant
-    /// Ce test vérifie que la fonction load_json_masters retourne bien une erreur
+    /// Ce test vérifie que la fonction load_json retourne bien une erreur
     /// lorsqu'elle tente de charger un fichier qui n'existe pas, plutôt que de provoquer un panic.
     #[test]
     fn test_load_json_masters_file_not_found() {
         // Act
-        let result = JsonLoader::load_json_masters("fichier_inexistant.json");
+        let result = JsonLoader::load_json::<Vec<Master>>("fichier_inexistant.json");
 
         // Assert
-        assert!(result.is_err());
+        assert!(matches!(
+            result,
+            Err(LoadError::NotFound { ref path }) if path == "fichier_inexistant.json"
+        ));
     }
 
     /// Test de chargement réussi d'une liste d'arènes depuis un fichier JSON
-    /// Ce test vérifie que la fonction load_json_arena peut correctement charger
+    /// Ce test vérifie que la fonction load_json peut correctement charger
     /// et désérialiser des données d'arènes depuis un fichier JSON valide.
-    /// Il valide le mapping des champs name et theme.
+    /// Il valide le mapping des champs name, theme et master_id, et les valeurs par défaut
+    /// de la difficulté et des conditions de déverrouillage.
     #[test]
     fn test_load_json_arena_success() {
         // Arrange
@@ -191,8 +497,11 @@ mod tests {
         let file_path = dir.path().join("test_arenas.json");
         let test_data = r#"[
             {
+                "id": "test_arena",
                 "name": "TEST_ARENA",
-                "theme": "Test Theme"
+                "theme": "Test Theme",
+                "master_id": "test_master",
+                "bouncer_id": "test_bouncer"
             }
         ]"#;
         
@@ -200,7 +509,7 @@ mod tests {
         file.write_all(test_data.as_bytes()).unwrap();
 
         // Act
-        let result = JsonLoader::load_json_arena(file_path.to_str().unwrap());
+        let result = JsonLoader::load_json::<Vec<Arena>>(file_path.to_str().unwrap());
 
         // Assert
         assert!(result.is_ok());
@@ -208,12 +517,15 @@ mod tests {
         assert_eq!(arenas.len(), 1);
         assert_eq!(arenas[0].name, "TEST_ARENA");
         assert_eq!(arenas[0].theme, "Test Theme");
+        assert_eq!(arenas[0].master_id, "test_master");
+        assert_eq!(arenas[0].difficulty, 0);
+        assert!(arenas[0].unlock_requirements.is_empty());
     }
 
     /// Test de chargement réussi d'une liste de videurs depuis un fichier JSON
-    /// Ce test vérifie que la fonction load_json_bouncers peut correctement charger
+    /// Ce test vérifie que la fonction load_json peut correctement charger
     /// et désérialiser des données de videurs avec leurs énigmes.
-    /// Il teste le système d'énigmes stockées dans un tableau.
+    /// Il teste les énigmes avec leur réponse, leurs distracteurs et leur indice optionnel.
     #[test]
     fn test_load_json_bouncers_success() {
         // Arrange
@@ -221,6 +533,7 @@ mod tests {
         let file_path = dir.path().join("test_bouncers.json");
         let test_data = r#"[
             {
+                "id": "test_bouncer",
                 "pnj": {
                     "caracter": {
                         "name": "Test Bouncer",
@@ -232,7 +545,19 @@ mod tests {
                     "job": "Doorman",
                     "dialogs": ["You shall not pass!"]
                 },
-                "enigmas": ["What has four legs but cannot walk?"]
+                "enigmas": [
+                    {
+                        "question": "What has four legs but cannot walk?",
+                        "answer": "A table",
+                        "distractors": ["A dog", "A chair"],
+                        "hint": "Furniture"
+                    },
+                    {
+                        "question": "What gets wetter the more it dries?",
+                        "answer": "A towel",
+                        "distractors": ["The sun"]
+                    }
+                ]
             }
         ]"#;
         
@@ -240,19 +565,23 @@ mod tests {
         file.write_all(test_data.as_bytes()).unwrap();
 
         // Act
-        let result = JsonLoader::load_json_bouncers(file_path.to_str().unwrap());
+        let result = JsonLoader::load_json::<Vec<Bouncer>>(file_path.to_str().unwrap());
 
         // Assert
         assert!(result.is_ok());
         let bouncers = result.unwrap();
         assert_eq!(bouncers.len(), 1);
         assert_eq!(bouncers[0].pnj.caracter.name, "Test Bouncer");
-        assert_eq!(bouncers[0].enigmas.len(), 1);
-        assert_eq!(bouncers[0].enigmas[0], "What has four legs but cannot walk?");
+        assert_eq!(bouncers[0].enigmas.len(), 2);
+        assert_eq!(bouncers[0].enigmas[0].question, "What has four legs but cannot walk?");
+        assert_eq!(bouncers[0].enigmas[0].answer, "A table");
+        assert_eq!(bouncers[0].enigmas[0].distractors.len(), 2);
+        assert_eq!(bouncers[0].enigmas[0].hint.as_deref(), Some("Furniture"));
+        assert!(bouncers[0].enigmas[1].hint.is_none());
     }
 
     /// Test de chargement réussi d'aptitudes depuis un fichier JSON
-    /// Ce test vérifie que la fonction load_json_aptitudes peut correctement charger
+    /// Ce test vérifie que la fonction load_json peut correctement charger
     /// et désérialiser des données d'aptitudes depuis un fichier JSON valide.
     /// Il valide le mapping des champs name, description, pp et power.
     #[test]
@@ -273,7 +602,7 @@ mod tests {
         file.write_all(test_data.as_bytes()).unwrap();
 
         // Act
-        let result = JsonLoader::load_json_aptitudes(file_path.to_str().unwrap());
+        let result = JsonLoader::load_json::<Vec<Aptitude>>(file_path.to_str().unwrap());
 
         // Assert
         assert!(result.is_ok());
@@ -283,78 +612,194 @@ mod tests {
         assert_eq!(aptitudes[0].description, "A test skill for testing");
         assert_eq!(aptitudes[0].pp, 10);
         assert_eq!(aptitudes[0].power, 1.5);
+        assert!(aptitudes[0].effect.is_none());
     }
 
-    /// Test de sauvegarde réussie d'un joueur dans un nouveau fichier JSON
-    /// Ce test vérifie que la fonction save_player_to_json peut correctement
-    /// sérialiser et sauvegarder un joueur dans un fichier JSON en utilisant
-    /// le niveau du joueur comme clé d'organisation des données.
-    /// Il valide également que le fichier est créé et contient les bonnes informations.
+    /// Test de sauvegarde réussie d'une partie dans un nouveau fichier JSON
+    /// Ce test vérifie que la fonction save_game peut correctement
+    /// sérialiser et sauvegarder une partie dans un fichier JSON avec sa version de format,
+    /// puis que load_save_game relit exactement le même joueur.
     #[test]
-    fn test_save_player_to_json_success() {
+    fn test_save_game_roundtrip() {
         // Arrange
         let dir = tempdir().unwrap();
         let file_path = dir.path().join("test_player.json");
         
-        // Créer un joueur de test avec la structure réelle basée sur le JSON
-        let test_player = Player::new(
-            "Test Player",
-            "Hero",
-            crate::models::badge::Badge { name: "Test Badge".to_string(), features: vec![] },
-            vec![], // inventory vide
-            vec![]  // aptitudes vides
-        );
+        let mut save = SaveGame::new(test_player("Test Player"));
+        save.progress.beaten_arenas.push("PASTIS".to_string());
 
         // Act
-        let result = JsonLoader::save_player_to_json(file_path.to_str().unwrap(), &test_player);
+        let result = JsonLoader::save_game(file_path.to_str().unwrap(), &save);
 
         // Assert
         assert!(result.is_ok());
         
         assert!(file_path.exists());
         let file_content = fs::read_to_string(&file_path).unwrap();
-        assert!(file_content.contains("Test Player"));
-        assert!(file_content.contains("\"1\""));  // level 1 par défaut
+        assert!(file_content.contains(&format!(
+            "\"format_version\": {}",
+            crate::models::save_game::SAVE_FORMAT_VERSION
+        )));
+
+        let loaded = JsonLoader::load_save_game(file_path.to_str().unwrap()).unwrap();
+        assert_eq!(loaded.player.caracter.name, "Test Player");
+        assert_eq!(loaded.progress.beaten_arenas, vec!["PASTIS"]);
     }
 
-    /// Test de mise à jour d'un fichier existant avec plusieurs joueurs
-    /// Ce test vérifie que la fonction save_player_to_json peut correctement
-    /// gérer l'ajout de plusieurs joueurs dans le même fichier JSON.
-    /// Il teste la fonctionnalité de lecture d'un fichier existant, l'ajout de nouvelles données,
-    /// et la réécriture du fichier avec toutes les informations préservées.
+    /// Test de rotation des sauvegardes de secours
+    /// Ce test vérifie que chaque sauvegarde conserve la version précédente en `.1.bak`,
+    /// que les plus anciennes sont décalées et qu'il n'en reste jamais plus de SAVE_BACKUP_COUNT.
     #[test]
-    fn test_save_player_update_existing_file() {
+    fn test_save_game_rotates_backups() {
         // Arrange
         let dir = tempdir().unwrap();
-        let file_path = dir.path().join("test_player_update.json");
-        
-        let player1 = Player::new(
-            "Player 1",
-            "Warrior",
-            crate::models::badge::Badge { name: "Badge 1".to_string(), features: vec![] },
-            vec![],
-            vec![]
-        );
-        
-        let mut player2 = Player::new(
-            "Player 2",
-            "Mage", 
-            crate::models::badge::Badge { name: "Badge 2".to_string(), features: vec![] },
-            vec![],
-            vec![]
-        );
-        player2.level = 2;
+        let file_path = dir.path().join("player_slot_1.json");
+        let file_path = file_path.to_str().unwrap();
+        let mut save = SaveGame::new(test_player("Rotation"));
 
         // Act
-        JsonLoader::save_player_to_json(file_path.to_str().unwrap(), &player1).unwrap();
-        JsonLoader::save_player_to_json(file_path.to_str().unwrap(), &player2).unwrap();
+        for level in 1..=SAVE_BACKUP_COUNT as u32 + 2 {
+            save.player.level = level;
+            JsonLoader::save_game(file_path, &save).unwrap();
+        }
 
         // Assert
-        let file_content = fs::read_to_string(&file_path).unwrap();
-        assert!(file_content.contains("Player 1"));
-        assert!(file_content.contains("Player 2"));
-        assert!(file_content.contains("\"1\""));
-        assert!(file_content.contains("\"2\""));
+        let current = JsonLoader::load_save_game(file_path).unwrap();
+        assert_eq!(current.player.level, SAVE_BACKUP_COUNT as u32 + 2);
+        for index in 1..=SAVE_BACKUP_COUNT {
+            let backup = JsonLoader::load_save_game(&JsonLoader::backup_path(file_path, index)).unwrap();
+            assert_eq!(backup.player.level, current.player.level - index as u32);
+        }
+        assert!(!Path::new(&JsonLoader::backup_path(file_path, SAVE_BACKUP_COUNT + 1)).exists());
+        assert!(!Path::new(&format!("{}.tmp", file_path)).exists());
+    }
+
+    /// Test d'une écriture de sauvegarde qui échoue
+    /// Ce test bloque le fichier temporaire avec un dossier du même nom et vérifie que
+    /// ni le fichier du slot ni ses sauvegardes de secours ne sont touchés.
+    #[test]
+    fn test_failed_save_keeps_backups() {
+        // Arrange
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("player_slot_1.json");
+        let file_path = file_path.to_str().unwrap();
+        let mut save = SaveGame::new(test_player("Prudent"));
+        for level in 1..=SAVE_BACKUP_COUNT as u32 + 1 {
+            save.player.level = level;
+            JsonLoader::save_game(file_path, &save).unwrap();
+        }
+        fs::create_dir(format!("{}.tmp", file_path)).unwrap();
+
+        // Act
+        save.player.level = 99;
+        let result = JsonLoader::save_game(file_path, &save);
+
+        // Assert
+        assert!(matches!(result, Err(SaveError::Write { .. })));
+        let current = JsonLoader::load_save_game(file_path).unwrap();
+        assert_eq!(current.player.level, SAVE_BACKUP_COUNT as u32 + 1);
+        for index in 1..=SAVE_BACKUP_COUNT {
+            let backup = JsonLoader::load_save_game(&JsonLoader::backup_path(file_path, index)).unwrap();
+            assert_eq!(backup.player.level, current.player.level - index as u32);
+        }
+    }
+
+    /// Test de restauration d'un slot dont le fichier principal est corrompu
+    /// Ce test simule un fichier tronqué par un crash et vérifie que la dernière
+    /// sauvegarde de secours valide est remise en place.
+    #[test]
+    fn test_restore_latest_backup_after_corruption() {
+        // Arrange
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("player_slot_2.json");
+        let file_path = file_path.to_str().unwrap();
+        let mut save = SaveGame::new(test_player("Survivant"));
+        JsonLoader::save_game(file_path, &save).unwrap();
+        save.player.level = 2;
+        JsonLoader::save_game(file_path, &save).unwrap();
+        fs::write(file_path, "{ \"format_version\": 1, \"player\": {").unwrap();
+        assert!(JsonLoader::load_save_game(file_path).is_err());
+
+        // Act
+        let restored = JsonLoader::restore_latest_backup(file_path);
+
+        // Assert
+        let restored = restored.unwrap();
+        assert_eq!(restored.player.caracter.name, "Survivant");
+        assert_eq!(restored.player.level, 1);
+        assert_eq!(JsonLoader::load_save_game(file_path).unwrap().player.level, 1);
+    }
+
+    /// Test de restauration sans aucune sauvegarde de secours
+    #[test]
+    fn test_restore_latest_backup_without_backup() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("player_slot_3.json");
+
+        let result = JsonLoader::restore_latest_backup(file_path.to_str().unwrap());
+
+        assert!(matches!(result, Err(SaveError::NoValidBackup { .. })));
+    }
+
+    /// Test des erreurs de chargement d'une sauvegarde
+    /// Un slot vide et une sauvegarde d'une version plus récente du jeu donnent
+    /// des erreurs distinctes, que l'appelant peut traiter différemment.
+    #[test]
+    fn test_load_save_game_error_kinds() {
+        let dir = tempdir().unwrap();
+        let missing_path = dir.path().join("player_slot_1.json");
+        let future_path = dir.path().join("player_slot_2.json");
+        fs::write(&future_path, r#"{ "format_version": 999 }"#).unwrap();
+
+        let missing = JsonLoader::load_save_game(missing_path.to_str().unwrap());
+        let future = JsonLoader::load_save_game(future_path.to_str().unwrap());
+
+        assert!(matches!(missing, Err(SaveError::Load(LoadError::NotFound { .. }))));
+        assert!(matches!(future, Err(SaveError::Migration { .. })));
+    }
+
+    /// Test de chargement d'une ancienne sauvegarde indexée par niveau
+    /// Ce test vérifie que load_save_game migre automatiquement les fichiers
+    /// écrits par l'ancienne version du jeu (map de joueurs par niveau, sans version)
+    /// au lieu d'échouer, en gardant le joueur de plus haut niveau.
+    #[test]
+    fn test_load_save_game_migrates_legacy_file() {
+        // Arrange
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("test_player_legacy.json");
+        let legacy_data = r#"{
+            "1": {
+                "aptitudes": [
+                    { "description": "Description de l'aptitude", "name": "Aptitude 3", "power": 5.0, "pp": 10 }
+                ],
+                "badge": { "features": ["Feature 1"], "name": "Badge 2" },
+                "caracter": { "bankroll": 0, "hp": 100, "name": "Player 1", "pp": 50, "style": "" },
+                "inventory": [],
+                "level": 1,
+                "reputation": 0
+            },
+            "2": {
+                "aptitudes": [],
+                "badge": { "features": [], "name": "Badge 1" },
+                "caracter": { "bankroll": 0, "hp": 100, "name": "Player 2", "pp": 50, "style": "" },
+                "inventory": [],
+                "level": 2,
+                "reputation": 0
+            }
+        }"#;
+
+        let mut file = File::create(&file_path).unwrap();
+        file.write_all(legacy_data.as_bytes()).unwrap();
+
+        // Act
+        let result = JsonLoader::load_save_game(file_path.to_str().unwrap());
+
+        // Assert
+        let save = result.unwrap();
+        assert_eq!(save.format_version, crate::models::save_game::SAVE_FORMAT_VERSION);
+        assert_eq!(save.player.caracter.name, "Player 2");
+        assert_eq!(save.player.level, 2);
+        assert!(save.progress.beaten_arenas.is_empty());
     }
 
     /// Test de gestion d'erreur pour un format JSON invalide
@@ -366,16 +811,23 @@ mod tests {
         // Arrange
         let dir = tempdir().unwrap();
         let file_path = dir.path().join("invalid.json");
-        let invalid_data = "{ invalid json }";
+        let invalid_data = "[\n  { invalid json }\n]";
         
         let mut file = File::create(&file_path).unwrap();
         file.write_all(invalid_data.as_bytes()).unwrap();
 
         // Act
-        let result = JsonLoader::load_json_masters(file_path.to_str().unwrap());
+        let result = JsonLoader::load_json::<Vec<Master>>(file_path.to_str().unwrap());
 
         // Assert
-        assert!(result.is_err());
+        match result {
+            Err(LoadError::Json { path, line, column, .. }) => {
+                assert_eq!(path, file_path.to_str().unwrap());
+                assert_eq!(line, 2);
+                assert_eq!(column, 5);
+            }
+            other => panic!("Erreur JSON attendue, obtenu {:?}", other.map(|m| m.len())),
+        }
     }
 
 }
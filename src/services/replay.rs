@@ -0,0 +1,263 @@
+use crate::services::engine::rng::GameRng;
+use crate::services::session::{SaveDirectory, SLOT_COUNT};
+use serde::de::DeserializeOwned;
+use serde_derive::{Deserialize, Serialize};
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+
+/// Interface qui a enregistré une session : c'est elle qui sait la rejouer
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum ReplayFrontend {
+    /// Interface terminal, rejouée sans affichage
+    Terminal,
+    Bevy,
+}
+
+impl fmt::Display for ReplayFrontend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayFrontend::Terminal => write!(f, "terminal"),
+            ReplayFrontend::Bevy => write!(f, "bevy"),
+        }
+    }
+}
+
+/// Entrée brute d'une interface, telle qu'elle arrive du joueur :
+/// une touche du terminal, un clic sur un bouton ou une touche du clavier dans Bevy.
+pub trait RecordedInput: serde::Serialize + DeserializeOwned + Clone {
+    /// Interface qui produit ces entrées
+    const FRONTEND: ReplayFrontend;
+}
+
+/// Session de jeu enregistrée, pour reproduire exactement ce qu'a vécu un testeur.
+/// L'en-tête donne l'état de départ, puis chaque entrée brute du joueur est rejouée
+/// par le même chemin que pendant l'enregistrement : menus et création de personnage compris.
+///
+/// Le fichier est au format JSON Lines : l'en-tête sur la première ligne,
+/// puis une ligne par entrée, ajoutée au fur et à mesure de la partie.
+#[derive(Debug, Clone)]
+pub struct Replay<I> {
+    pub header: ReplayHeader,
+    pub inputs: Vec<ReplayInput<I>>,
+}
+
+/// Début d'une session enregistrée : tout ce qu'il faut pour repartir du même état
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ReplayHeader {
+    pub frontend: ReplayFrontend,
+    /// Graine du hasard de toutes les parties de la session
+    pub seed: u64,
+    /// Contenu des fichiers des slots au début de la session, `None` pour un slot vide
+    pub slots: Vec<Option<String>>,
+    /// Slot chargé directement au lancement
+    pub slot: Option<usize>,
+    /// Arène dans laquelle la session commence directement
+    pub arena: Option<String>,
+}
+
+impl ReplayHeader {
+    /// Décrit le début d'une nouvelle session : la graine donnée, ou une graine tirée
+    /// au hasard pour toute la session, et une copie des slots de sauvegarde
+    ///
+    /// # Arguments
+    /// * `save_dir` - Le dossier des sauvegardes des slots.
+    /// * `seed` - La graine demandée en ligne de commande.
+    /// * `slot` - Le slot chargé directement au lancement.
+    /// * `arena` - L'arène de départ.
+    pub fn capture<I: RecordedInput>(
+        save_dir: &SaveDirectory,
+        seed: Option<u64>,
+        slot: Option<usize>,
+        arena: Option<String>,
+    ) -> Self {
+        Self {
+            frontend: I::FRONTEND,
+            seed: seed.unwrap_or_else(|| GameRng::default().seed()),
+            slots: (0..SLOT_COUNT)
+                .map(|slot| fs::read_to_string(save_dir.slot_path(slot)).ok())
+                .collect(),
+            slot,
+            arena,
+        }
+    }
+}
+
+/// Entrée du joueur, datée depuis le début de la session
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ReplayInput<I> {
+    pub time_ms: u64,
+    pub input: I,
+}
+
+/// Enregistre les entrées du joueur dans un fichier de rejeu.
+/// Chaque entrée est ajoutée en fin de fichier et écrite aussitôt : même si le jeu plante
+/// ou est tué, le fichier contient la session jusqu'à la dernière entrée.
+pub struct ReplayRecorder<I> {
+    path: String,
+    writer: BufWriter<File>,
+    started: Instant,
+    input: PhantomData<I>,
+}
+
+impl<I: RecordedInput> ReplayRecorder<I> {
+    /// Crée le fichier de rejeu et y écrit l'en-tête de la session
+    ///
+    /// # Arguments
+    /// * `path` - Le fichier de rejeu, remplacé s'il existe déjà.
+    /// * `header` - L'état de départ de la session.
+    pub fn create(path: String, header: &ReplayHeader) -> io::Result<Self> {
+        let mut writer = BufWriter::new(File::create(&path)?);
+        serde_json::to_writer(&mut writer, header)?;
+        writer.write_all(b"\n")?;
+        writer.flush()?;
+        Ok(Self {
+            path,
+            writer,
+            started: Instant::now(),
+            input: PhantomData,
+        })
+    }
+
+    /// Ajoute une entrée du joueur à la fin du fichier.
+    /// La ligne est préparée dans le tampon puis écrite d'un bloc.
+    pub fn record(&mut self, input: &I) {
+        let line = ReplayInput {
+            time_ms: self.started.elapsed().as_millis() as u64,
+            input: input.clone(),
+        };
+        let result = serde_json::to_writer(&mut self.writer, &line)
+            .map_err(io::Error::from)
+            .and_then(|_| self.writer.write_all(b"\n"))
+            .and_then(|_| self.writer.flush());
+        if let Err(e) = result {
+            eprintln!("Erreur lors de l'enregistrement du rejeu {}: {}", self.path, e);
+        }
+    }
+}
+
+/// Lecture d'une session enregistrée au rythme où elle a été jouée.
+/// Les slots enregistrés sont recréés dans un dossier temporaire, supprimé avec le lecteur :
+/// le rejeu ne touche pas aux sauvegardes du joueur.
+pub struct ReplayPlayer<I> {
+    pub replay: Replay<I>,
+    save_dir: TempDir,
+    next_input: usize,
+    started: Instant,
+}
+
+impl<I> ReplayPlayer<I> {
+    /// Recrée les slots de la session, puis prépare la lecture qui commence aussitôt
+    pub fn new(replay: Replay<I>) -> io::Result<Self> {
+        let save_dir = tempfile::tempdir()?;
+        let slots = SaveDirectory(save_dir.path().to_string_lossy().into_owned());
+        for (slot, data) in replay.header.slots.iter().enumerate() {
+            if let Some(data) = data {
+                fs::write(slots.slot_path(slot), data)?;
+            }
+        }
+        Ok(Self {
+            replay,
+            save_dir,
+            next_input: 0,
+            started: Instant::now(),
+        })
+    }
+
+    /// Dossier des slots recréés, à utiliser à la place du dossier des sauvegardes
+    pub fn save_dir(&self) -> SaveDirectory {
+        SaveDirectory(self.save_dir.path().to_string_lossy().into_owned())
+    }
+
+    /// Prochaine entrée à rejouer, si son heure est venue depuis le début de la lecture
+    pub fn next_due(&self) -> Option<&I> {
+        let input = self.replay.inputs.get(self.next_input)?;
+        (Duration::from_millis(input.time_ms) <= self.started.elapsed()).then_some(&input.input)
+    }
+
+    /// Passe à l'entrée suivante, une fois la précédente rejouée
+    pub fn advance(&mut self) {
+        self.next_input += 1;
+    }
+
+    /// Vrai quand toutes les entrées ont été rejouées
+    pub fn is_finished(&self) -> bool {
+        self.next_input >= self.replay.inputs.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::json_loader::JsonLoader;
+    use tempfile::tempdir;
+
+    // Entrée de test, enregistrée comme celles d'une interface
+    impl RecordedInput for String {
+        const FRONTEND: ReplayFrontend = ReplayFrontend::Terminal;
+    }
+
+    /// Test d'une session enregistrée puis relue
+    /// Les entrées sont sur le disque dès qu'elles sont enregistrées, dans l'ordre,
+    /// et les slots de départ sont recréés sans toucher au dossier d'origine.
+    #[test]
+    fn test_recorded_inputs_and_slots_are_read_back() {
+        let dir = tempdir().unwrap();
+        let save_dir = SaveDirectory(dir.path().to_str().unwrap().to_string());
+        fs::write(save_dir.slot_path(1), "{\"slot\": 2}").unwrap();
+        let path = dir.path().join("session.jsonl").to_str().unwrap().to_string();
+
+        let header = ReplayHeader::capture::<String>(&save_dir, Some(7), Some(1), None);
+        let mut recorder = ReplayRecorder::create(path.clone(), &header).unwrap();
+        recorder.record(&"haut".to_string());
+        recorder.record(&"entree".to_string());
+
+        // L'enregistreur est encore actif, comme si le jeu venait de planter
+        let replay = JsonLoader::load_replay::<String>(&path).unwrap();
+        drop(recorder);
+        assert_eq!(replay.header, header);
+        assert_eq!(replay.header.slots, vec![None, Some("{\"slot\": 2}".to_string()), None]);
+        let inputs: Vec<String> = replay.inputs.iter().map(|input| input.input.clone()).collect();
+        assert_eq!(inputs, vec!["haut", "entree"]);
+
+        let mut player = ReplayPlayer::new(replay).unwrap();
+        let replay_dir = player.save_dir();
+        assert_ne!(replay_dir, save_dir);
+        assert_eq!(fs::read_to_string(replay_dir.slot_path(1)).unwrap(), "{\"slot\": 2}");
+        let mut replayed = Vec::new();
+        while !player.is_finished() {
+            if let Some(input) = player.next_due() {
+                replayed.push(input.clone());
+                player.advance();
+            }
+        }
+        assert_eq!(replayed, inputs);
+    }
+
+    /// Test d'un fichier de rejeu coupé ou venant d'une autre interface
+    /// Une dernière ligne coupée par un arrêt brutal est ignorée,
+    /// une session d'une autre interface est refusée.
+    #[test]
+    fn test_truncated_or_foreign_replay() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("session.jsonl").to_str().unwrap().to_string();
+        let save_dir = SaveDirectory(dir.path().to_str().unwrap().to_string());
+        let mut header = ReplayHeader::capture::<String>(&save_dir, Some(1), None, None);
+        let header_line = serde_json::to_string(&header).unwrap();
+
+        let truncated = format!(
+            "{}\n{{\"time_ms\":3,\"input\":\"a\"}}\n{{\"time_ms\":5,\"inp",
+            header_line
+        );
+        fs::write(&path, truncated).unwrap();
+        let replay = JsonLoader::load_replay::<String>(&path).unwrap();
+        assert_eq!(replay.inputs.len(), 1);
+
+        header.frontend = ReplayFrontend::Bevy;
+        fs::write(&path, format!("{}\n", serde_json::to_string(&header).unwrap())).unwrap();
+        assert!(JsonLoader::load_replay::<String>(&path).is_err());
+    }
+}
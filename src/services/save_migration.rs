@@ -0,0 +1,235 @@
+use serde_json::{json, Value};
+
+use crate::models::inventory::MAX_STACK_SIZE;
+use crate::models::save_game::{CampaignProgress, GameSettings, SAVE_FORMAT_VERSION};
+
+/// Une étape de migration : transforme une sauvegarde de la version N vers la version N + 1
+type Migration = fn(Value) -> Result<Value, String>;
+
+/// Chaîne des migrations, l'index correspond à la version de départ.
+/// Pour ajouter une version, on incrémente `SAVE_FORMAT_VERSION` et on ajoute une étape ici.
+const MIGRATIONS: [Migration; SAVE_FORMAT_VERSION as usize] =
+    [migrate_v0_to_v1, migrate_v1_to_v2, migrate_v2_to_v3];
+
+/// Retourne la version d'une sauvegarde brute.
+/// Les fichiers sans `format_version` sont des sauvegardes historiques (version 0).
+pub fn detect_version(save: &Value) -> u32 {
+    save.get("format_version")
+        .and_then(Value::as_u64)
+        .map(|version| version as u32)
+        .unwrap_or(0)
+}
+
+/// Met à jour une sauvegarde brute jusqu'à la version actuelle en appliquant
+/// les migrations une par une.
+/// Retourne une erreur si la sauvegarde vient d'une version plus récente du jeu
+/// ou si une migration échoue.
+///
+/// # Arguments
+/// * `save` - Le contenu JSON de la sauvegarde, dans n'importe quelle version connue.
+pub fn migrate_to_current(mut save: Value) -> Result<Value, String> {
+    let mut version = detect_version(&save);
+    if version > SAVE_FORMAT_VERSION {
+        return Err(format!(
+            "Sauvegarde en version {} non supportee (version maximale : {})",
+            version, SAVE_FORMAT_VERSION
+        ));
+    }
+
+    while version < SAVE_FORMAT_VERSION {
+        save = MIGRATIONS[version as usize](save)?;
+        version += 1;
+        save["format_version"] = json!(version);
+    }
+
+    Ok(save)
+}
+
+// Version 0 -> 1 : on enveloppe le joueur historique dans une `SaveGame`
+fn migrate_v0_to_v1(save: Value) -> Result<Value, String> {
+    let player = legacy_player(save)?;
+
+    Ok(json!({
+        "format_version": 1,
+        "player": player,
+        "progress": serde_json::to_value(CampaignProgress::default()).map_err(|e| e.to_string())?,
+        "settings": serde_json::to_value(GameSettings::default()).map_err(|e| e.to_string())?,
+    }))
+}
+
+// Version 1 -> 2 : l'inventaire, une liste d'ingrédients répétés, devient une liste de piles
+// (nom de l'ingrédient et quantité), dans l'ordre de première apparition
+fn migrate_v1_to_v2(mut save: Value) -> Result<Value, String> {
+    let Some(inventory) = save.pointer_mut("/player/inventory") else {
+        return Ok(save);
+    };
+    let Value::Array(ingredients) = inventory.take() else {
+        return Err("Inventaire invalide : liste attendue".to_string());
+    };
+
+    let mut stacks: Vec<(String, u32)> = Vec::new();
+    for ingredient in ingredients {
+        let name = ingredient
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or_else(|| "Ingredient d'inventaire sans nom".to_string())?;
+        match stacks.iter_mut().find(|(id, _)| id == name) {
+            Some((_, quantity)) => *quantity = (*quantity + 1).min(MAX_STACK_SIZE),
+            None => stacks.push((name.to_string(), 1)),
+        }
+    }
+
+    *inventory = stacks
+        .into_iter()
+        .map(|(id, quantity)| json!({ "ingredient_id": id, "quantity": quantity }))
+        .collect();
+    Ok(save)
+}
+
+// Version 2 -> 3 : les HP et PP maximum du joueur deviennent des champs à part.
+// Jusque-là `caracter.hp` et `caracter.pp` servaient de maximums, on les reprend tels quels.
+fn migrate_v2_to_v3(mut save: Value) -> Result<Value, String> {
+    let Some(Value::Object(player)) = save.get_mut("player") else {
+        return Err("Sauvegarde sans joueur".to_string());
+    };
+    let caracter = player
+        .get("caracter")
+        .cloned()
+        .ok_or_else(|| "Joueur sans caracter".to_string())?;
+
+    player.insert("max_hp".to_string(), caracter.get("hp").cloned().unwrap_or(json!(100)));
+    player.insert("max_pp".to_string(), caracter.get("pp").cloned().unwrap_or(json!(50)));
+    Ok(save)
+}
+
+// Extrait le joueur d'une sauvegarde historique. Trois formes existent :
+// - un joueur seul (`{"caracter": ...}`)
+// - le format de `roundSaver.json` (`{"index": 5, "player": {...}}`)
+// - une map de joueurs indexée par niveau (`{"1": {...}, "2": {...}}`), on garde le plus haut niveau
+fn legacy_player(save: Value) -> Result<Value, String> {
+    let Value::Object(mut entries) = save else {
+        return Err("Sauvegarde historique invalide : objet JSON attendu".to_string());
+    };
+
+    if entries.contains_key("caracter") {
+        return Ok(Value::Object(entries));
+    }
+
+    if let Some(player) = entries.remove("player") {
+        return Ok(player);
+    }
+
+    entries
+        .into_iter()
+        .filter(|(_, player)| player.get("caracter").is_some())
+        .filter_map(|(level, player)| level.parse::<u32>().ok().map(|level| (level, player)))
+        .max_by_key(|(level, _)| *level)
+        .map(|(_, player)| player)
+        .ok_or_else(|| "Aucun joueur trouve dans la sauvegarde historique".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn legacy_player_json(name: &str, level: u32) -> Value {
+        json!({
+            "aptitudes": [],
+            "badge": { "name": "Badge 1", "features": [] },
+            "caracter": { "name": name, "style": "", "hp": 100, "pp": 50, "bankroll": 0 },
+            "inventory": [],
+            "level": level,
+            "reputation": 0
+        })
+    }
+
+    /// Test de migration d'une sauvegarde de slot indexée par niveau
+    /// Le joueur de plus haut niveau doit être conservé dans la nouvelle enveloppe.
+    #[test]
+    fn test_migrate_level_map_keeps_highest_level() {
+        let legacy = json!({
+            "1": legacy_player_json("Elendil", 1),
+            "2": legacy_player_json("Elendil", 2)
+        });
+
+        let migrated = migrate_to_current(legacy).unwrap();
+
+        assert_eq!(detect_version(&migrated), SAVE_FORMAT_VERSION);
+        assert_eq!(migrated["player"]["level"], 2);
+        assert_eq!(migrated["progress"]["beaten_arenas"], json!([]));
+    }
+
+    /// Test de migration du format `{index, player}` de `roundSaver.json`
+    #[test]
+    fn test_migrate_round_saver_format() {
+        let legacy = json!({ "index": 5, "player": legacy_player_json("Frodo", 1) });
+
+        let migrated = migrate_to_current(legacy).unwrap();
+
+        assert_eq!(migrated["player"]["caracter"]["name"], "Frodo");
+    }
+
+    /// Test d'une sauvegarde déjà à jour
+    /// Elle ne doit pas être modifiée.
+    #[test]
+    fn test_current_version_is_untouched() {
+        let save = json!({
+            "format_version": SAVE_FORMAT_VERSION,
+            "player": legacy_player_json("Arwen", 3)
+        });
+
+        assert_eq!(migrate_to_current(save.clone()).unwrap(), save);
+    }
+
+    /// Test d'une sauvegarde venant d'une version future du jeu
+    /// Elle doit être refusée plutôt que mal interprétée.
+    #[test]
+    fn test_future_version_is_rejected() {
+        let save = json!({ "format_version": SAVE_FORMAT_VERSION + 1 });
+
+        assert!(migrate_to_current(save).is_err());
+    }
+
+    /// Test d'une sauvegarde historique sans joueur
+    #[test]
+    fn test_legacy_without_player_is_rejected() {
+        assert!(migrate_to_current(json!({ "1": { "foo": "bar" } })).is_err());
+    }
+
+    /// Test de migration de l'inventaire en piles
+    /// Les ingrédients répétés d'une sauvegarde en version 1 sont regroupés avec leur quantité.
+    #[test]
+    fn test_migrate_inventory_to_stacks() {
+        let mut player = legacy_player_json("Gimli", 1);
+        let pastis = json!({ "name": "Pastis", "description": "", "price": 10, "type_ing": "Alcool" });
+        let eau = json!({ "name": "Eau", "description": "", "price": 0, "type_ing": "Soft" });
+        player["inventory"] = json!([pastis.clone(), eau, pastis]);
+        let save = json!({ "format_version": 1, "player": player });
+
+        let migrated = migrate_to_current(save).unwrap();
+
+        assert_eq!(
+            migrated["player"]["inventory"],
+            json!([
+                { "ingredient_id": "Pastis", "quantity": 2 },
+                { "ingredient_id": "Eau", "quantity": 1 }
+            ])
+        );
+    }
+
+    /// Test de migration des HP et PP maximum
+    /// Les HP et PP d'une sauvegarde en version 2 deviennent les maximums du joueur.
+    #[test]
+    fn test_migrate_max_hp_and_pp() {
+        let mut player = legacy_player_json("Legolas", 2);
+        player["caracter"]["hp"] = json!(120);
+        player["caracter"]["pp"] = json!(60);
+        let save = json!({ "format_version": 2, "player": player });
+
+        let migrated = migrate_to_current(save).unwrap();
+
+        assert_eq!(migrated["player"]["max_hp"], 120);
+        assert_eq!(migrated["player"]["max_pp"], 60);
+        assert_eq!(migrated["player"]["caracter"]["hp"], 120);
+    }
+}
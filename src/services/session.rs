@@ -0,0 +1,250 @@
+use crate::models::caracter::player::Player;
+use crate::models::save_game::SaveGame;
+use crate::services::content::GameContent;
+use crate::services::engine::GameEngine;
+use crate::services::json_loader::JsonLoader;
+use std::path::Path;
+
+/// Nombre de slots de sauvegarde proposés au joueur
+pub const SLOT_COUNT: usize = 3;
+
+/// Dossier des sauvegardes des slots, `save` par défaut.
+/// Il peut être changé en ligne de commande pour jouer avec d'autres sauvegardes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaveDirectory(pub String);
+
+impl Default for SaveDirectory {
+    fn default() -> Self {
+        Self("save".to_string())
+    }
+}
+
+impl SaveDirectory {
+    /// Chemin du fichier de sauvegarde d'un slot
+    pub fn slot_path(&self, slot: usize) -> String {
+        format!("{}/player_slot_{}.json", self.0, slot + 1)
+    }
+}
+
+/// Joueur de la partie en cours, chargé depuis le slot sélectionné à l'entrée en jeu.
+/// Sa sauvegarde est réécrite à la fin de chaque arène et quand le joueur quitte le jeu.
+#[derive(Default)]
+pub struct CurrentPlayer {
+    pub slot: Option<usize>,
+    pub save: Option<SaveGame>,
+    pub save_dir: SaveDirectory,
+}
+
+impl CurrentPlayer {
+
+    /// Recopie la progression du joueur depuis le moteur de jeu puis écrit la sauvegarde du slot
+    ///
+    /// # Arguments
+    /// * `engine` - Le moteur de jeu qui contient le joueur à jour.
+    pub fn save_from(&mut self, engine: &GameEngine) {
+        let (Some(slot), Some(save), Some(player)) =
+            (self.slot, self.save.as_mut(), engine.player.as_ref())
+        else {
+            return;
+        };
+
+        save.player = player.clone();
+        save.progress.beaten_arenas = engine.beaten_arenas.clone();
+        save.progress.seed = Some(engine.rng.seed());
+        if let Some(arena) = engine
+            .selected_arena_index
+            .and_then(|index| engine.arenas.get(index))
+        {
+            save.progress.last_arena = Some(arena.id.clone());
+        }
+
+        let file_path = self.save_dir.slot_path(slot);
+        if let Err(e) = JsonLoader::save_game(&file_path, save) {
+            println!("Erreur lors de la sauvegarde du joueur: {}", e);
+        }
+    }
+}
+
+// Structure pour stocker les informations des slots
+pub struct SlotInfo {
+    // Vecteur de 3 slots chaque élément contient le nom du joueur ou None si vide
+    pub info: Vec<Option<String>>,
+    // Vrai si le fichier du slot est illisible mais qu'une sauvegarde de secours peut être restaurée
+    pub restorable: Vec<bool>,
+}
+
+impl Default for SlotInfo {
+    fn default() -> Self {
+        Self {
+            info: vec![None; SLOT_COUNT],
+            restorable: vec![false; SLOT_COUNT],
+        }
+    }
+}
+
+impl SlotInfo {
+    /// Lit les 3 slots de sauvegarde : le nom du joueur de chaque slot,
+    /// ou si le fichier est illisible, la possibilité de le restaurer depuis une sauvegarde de secours
+    ///
+    /// # Arguments
+    /// * `save_dir` - Le dossier des sauvegardes des slots.
+    pub fn load(save_dir: &SaveDirectory) -> Self {
+        // On crée le dossier de sauvegarde si nécessaire
+        JsonLoader::ensure_save_directory(&save_dir.0);
+
+        let mut slot_info = Self::default();
+
+        // On vérifie chaque slot (1, 2, 3)
+
+        for i in 0..SLOT_COUNT {
+            let file_path = save_dir.slot_path(i);
+            if Path::new(&file_path).exists() {
+                // On charge la sauvegarde (migree si besoin) pour extraire le nom du joueur
+                match JsonLoader::load_save_game(&file_path) {
+                    Ok(save) => slot_info.info[i] = Some(save.player.caracter.name),
+                    Err(e) => {
+                        println!(
+                            "Erreur lors de la lecture de la sauvegarde du slot {}: {}",
+                            i + 1,
+                            e
+                        );
+                        // On propose de restaurer une sauvegarde de secours si possible
+                        slot_info.restorable[i] =
+                            JsonLoader::latest_valid_backup(&file_path).is_some();
+                    }
+                }
+            }
+        }
+        slot_info
+    }
+}
+
+/// Données temporaires de création de personnage, remplies par l'écran de création
+#[derive(Default)]
+pub struct PlayerCreationData {
+    pub name: String,
+    pub style: String,
+    pub selected_badge_index: Option<usize>,
+    pub selected_aptitudes: Vec<usize>,
+}
+
+/// Nombre d'aptitudes à choisir à la création : les emplacements d'aptitudes du premier
+/// palier de `levels.json`, dans la limite des aptitudes existantes (au moins une).
+pub fn aptitude_picks(content: &GameContent) -> usize {
+    content
+        .levels
+        .first()
+        .map_or(1, |step| step.aptitude_slots.max(1))
+        .min(content.aptitudes.len())
+}
+
+/// Ajoute ou retire une aptitude de la sélection.
+/// Si la sélection est pleine, la plus ancienne aptitude choisie est remplacée.
+///
+/// # Arguments
+/// * `selected` - Les index des aptitudes déjà choisies, dans l'ordre du choix.
+/// * `index` - L'aptitude cliquée.
+/// * `picks` - Le nombre d'aptitudes autorisé.
+pub fn toggle_aptitude(selected: &mut Vec<usize>, index: usize, picks: usize) {
+    if selected.contains(&index) {
+        selected.retain(|&i| i != index);
+        return;
+    }
+    if picks == 0 {
+        return;
+    }
+    while selected.len() >= picks {
+        selected.remove(0);
+    }
+    selected.push(index);
+}
+
+/// Crée un nouveau joueur à partir des choix de création et des données du jeu :
+/// le badge de départ et les aptitudes sont ceux de `badges.json` et `aptitudes.json`.
+/// Retourne un message si un choix manque.
+///
+/// # Arguments
+/// * `creation_data` - Le nom et les index choisis sur l'écran de création.
+/// * `content` - Le contenu du jeu chargé au démarrage.
+pub fn create_player(creation_data: &PlayerCreationData, content: &GameContent) -> Result<Player, String> {
+    if creation_data.name.is_empty() {
+        return Err("Veuillez choisir un nom avant de creer le personnage.".to_string());
+    }
+    let badge = creation_data
+        .selected_badge_index
+        .and_then(|index| content.badges.get(index))
+        .ok_or_else(|| "Veuillez choisir un badge de depart.".to_string())?;
+
+    let picks = aptitude_picks(content);
+    let aptitudes: Vec<_> = creation_data
+        .selected_aptitudes
+        .iter()
+        .filter_map(|&index| content.aptitudes.get(index).cloned())
+        .collect();
+    if aptitudes.len() != picks {
+        return Err(format!("Veuillez choisir {} aptitude(s).", picks));
+    }
+
+    Ok(Player::new(
+        &creation_data.name,
+        &creation_data.style,
+        badge.clone(),
+        Vec::new(),
+        aptitudes,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn creation_data(badge: Option<usize>, aptitudes: Vec<usize>) -> PlayerCreationData {
+        PlayerCreationData {
+            name: "Elendil".to_string(),
+            style: String::new(),
+            selected_badge_index: badge,
+            selected_aptitudes: aptitudes,
+        }
+    }
+
+    /// Test de création d'un joueur depuis les données du jeu
+    /// Le badge et les aptitudes choisis sont ceux des fichiers, avec leurs effets.
+    #[test]
+    fn test_create_player_from_data() {
+        let content = GameContent::load("assets").unwrap();
+        let picks: Vec<usize> = (0..aptitude_picks(&content)).collect();
+
+        let player = create_player(&creation_data(Some(1), picks.clone()), &content).unwrap();
+
+        assert_eq!(player.badge.name, content.badges[1].name);
+        assert_eq!(player.aptitudes.len(), picks.len());
+        assert_eq!(player.aptitudes[0].name, content.aptitudes[0].name);
+        assert_eq!(player.aptitudes[0].effect, content.aptitudes[0].effect);
+    }
+
+    /// Test d'une création incomplète
+    /// Sans badge ou avec un mauvais nombre d'aptitudes, le joueur n'est pas créé.
+    #[test]
+    fn test_create_player_requires_choices() {
+        let content = GameContent::load("assets").unwrap();
+
+        assert!(create_player(&creation_data(None, vec![0, 1]), &content).is_err());
+        assert!(create_player(&creation_data(Some(0), vec![]), &content).is_err());
+        assert!(create_player(&creation_data(Some(9), vec![0, 1]), &content).is_err());
+    }
+
+    /// Test du nombre d'aptitudes choisies
+    /// Une sélection pleine remplace l'aptitude choisie en premier.
+    #[test]
+    fn test_toggle_aptitude_keeps_pick_limit() {
+        let mut selected = Vec::new();
+
+        toggle_aptitude(&mut selected, 0, 2);
+        toggle_aptitude(&mut selected, 1, 2);
+        toggle_aptitude(&mut selected, 2, 2);
+        assert_eq!(selected, vec![1, 2]);
+
+        toggle_aptitude(&mut selected, 1, 2);
+        assert_eq!(selected, vec![2]);
+    }
+}
@@ -0,0 +1,687 @@
+use crate::models::save_game::SaveGame;
+use crate::services::content::GameContent;
+use crate::services::engine::event::GameEvent;
+use crate::services::engine::state::GameScreenType;
+use crate::services::engine::{GameCommand, GameEngine};
+use crate::services::json_loader::{JsonLoader, LoadError};
+use crate::services::replay::{RecordedInput, ReplayFrontend, ReplayHeader, ReplayRecorder};
+use crate::services::tui::view::{game_view, TuiAction, TuiChoice, TuiView};
+use crate::services::session::{
+    aptitude_picks, create_player, toggle_aptitude, CurrentPlayer, PlayerCreationData,
+    SaveDirectory, SlotInfo,
+};
+use serde_derive::{Deserialize, Serialize};
+
+/// Touches du clavier comprises par l'interface terminal
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TuiKey {
+    Up,
+    Down,
+    Enter,
+    Backspace,
+    Esc,
+    Char(char),
+}
+
+/// Entrée du joueur dans l'interface terminal, telle qu'elle est enregistrée pour le rejeu :
+/// une touche, ou un numéro de choix et la fin de l'entrée en mode sans affichage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TuiInput {
+    Key(TuiKey),
+    Choose(usize),
+    Quit,
+}
+
+impl RecordedInput for TuiInput {
+    const FRONTEND: ReplayFrontend = ReplayFrontend::Terminal;
+}
+
+/// Étapes de l'interface terminal, dans l'ordre d'une partie
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TuiPhase {
+    LoadErrors,
+    Menu,
+    Slots { load_game: bool },
+    Creation,
+    Game,
+    Quit,
+}
+
+/// Champ de texte en cours de saisie sur l'écran de création
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CreationField {
+    Name,
+    Style,
+}
+
+/// Interface terminal du jeu, sans dépendance au terminal lui-même :
+/// elle reçoit des touches et produit l'écran à afficher.
+/// La partie est jouée par le même moteur et les mêmes sauvegardes que l'interface Bevy.
+pub struct TuiApp {
+    pub phase: TuiPhase,
+    pub selected: usize,
+    pub engine: GameEngine,
+    content: GameContent,
+    load_errors: Vec<LoadError>,
+    message: Option<String>,
+    slot_info: SlotInfo,
+    selected_slot: Option<usize>,
+    creation_data: PlayerCreationData,
+    editing: Option<CreationField>,
+    current_player: CurrentPlayer,
+    save_dir: SaveDirectory,
+    seed: Option<u64>,
+    recorder: Option<ReplayRecorder<TuiInput>>,
+}
+
+impl TuiApp {
+    /// Crée l'interface terminal avec le contenu du jeu.
+    /// Les erreurs de chargement sont affichées avant le menu principal.
+    ///
+    /// # Arguments
+    /// * `content` - Le contenu du jeu chargé au démarrage.
+    /// * `load_errors` - Les erreurs rencontrées pendant le chargement du contenu.
+    /// * `save_dir` - Le dossier des sauvegardes des slots.
+    /// * `seed` - La graine du hasard des parties, aléatoire si elle n'est pas donnée.
+    pub fn new(
+        content: GameContent,
+        load_errors: Vec<LoadError>,
+        save_dir: SaveDirectory,
+        seed: Option<u64>,
+    ) -> Self {
+        Self {
+            phase: if load_errors.is_empty() {
+                TuiPhase::Menu
+            } else {
+                TuiPhase::LoadErrors
+            },
+            selected: 0,
+            engine: GameEngine::default(),
+            content,
+            load_errors,
+            message: None,
+            slot_info: SlotInfo::default(),
+            selected_slot: None,
+            creation_data: PlayerCreationData::default(),
+            editing: None,
+            current_player: CurrentPlayer::default(),
+            save_dir,
+            seed,
+            recorder: None,
+        }
+    }
+
+    /// Vrai tant que le joueur n'a pas quitté le jeu
+    pub fn is_running(&self) -> bool {
+        self.phase != TuiPhase::Quit
+    }
+
+    /// Écran à afficher pour l'étape en cours
+    pub fn view(&self) -> TuiView {
+        let mut view = match self.phase {
+            TuiPhase::LoadErrors => self.load_errors_view(),
+            TuiPhase::Menu => menu_view(),
+            TuiPhase::Slots { load_game } => self.slots_view(load_game),
+            TuiPhase::Creation => self.creation_view(),
+            TuiPhase::Game => game_view(&self.engine),
+            TuiPhase::Quit => TuiView::default(),
+        };
+        if let Some(message) = &self.message {
+            view.line(message.clone());
+        }
+        view
+    }
+
+    /// Traite une entrée du joueur, ajoutée d'abord au fichier de rejeu si la session est enregistrée.
+    /// Retourne faux pour un numéro de choix qui n'existe pas.
+    ///
+    /// # Arguments
+    /// * `input` - La touche pressée ou le numéro de choix tapé.
+    pub fn handle_input(&mut self, input: TuiInput) -> bool {
+        if let Some(recorder) = self.recorder.as_mut() {
+            recorder.record(&input);
+        }
+        match input {
+            TuiInput::Key(key) => self.handle_key(key),
+            TuiInput::Choose(number) => return self.choose(number),
+            TuiInput::Quit => self.quit(),
+        }
+        true
+    }
+
+    /// Traite une touche du clavier
+    ///
+    /// # Arguments
+    /// * `key` - La touche pressée.
+    pub fn handle_key(&mut self, key: TuiKey) {
+        let view = self.view();
+
+        // Les caractères vont dans le champ de saisie s'il y en a un
+        if let Some(input) = &view.input {
+            match key {
+                TuiKey::Char(c) => {
+                    self.edit_input(format!("{}{}", input, c));
+                    return;
+                }
+                TuiKey::Backspace => {
+                    let mut text = input.clone();
+                    text.pop();
+                    self.edit_input(text);
+                    return;
+                }
+                TuiKey::Enter | TuiKey::Esc if self.editing.is_some() => {
+                    self.editing = None;
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        match key {
+            TuiKey::Up => self.selected = self.selected.saturating_sub(1),
+            TuiKey::Down => {
+                self.selected = (self.selected + 1).min(view.choices.len().saturating_sub(1))
+            }
+            TuiKey::Enter => {
+                if let Some(choice) = view.choices.get(self.selected) {
+                    self.activate(choice.action.clone());
+                }
+            }
+            // Raccourci : le numéro d'un choix l'active directement
+            TuiKey::Char(c) => {
+                if let Some(digit) = c.to_digit(10) {
+                    self.choose(digit as usize);
+                }
+            }
+            TuiKey::Esc => self.go_back(),
+            TuiKey::Backspace => {}
+        }
+    }
+
+    /// Active le choix numéroté de l'écran courant, en comptant à partir de 1.
+    /// Retourne faux si l'écran n'a pas de choix avec ce numéro.
+    ///
+    /// # Arguments
+    /// * `number` - Le numéro affiché devant le choix.
+    pub fn choose(&mut self, number: usize) -> bool {
+        let view = self.view();
+        let Some(choice) = number
+            .checked_sub(1)
+            .and_then(|index| view.choices.get(index))
+        else {
+            return false;
+        };
+        self.selected = number - 1;
+        self.activate(choice.action.clone());
+        true
+    }
+
+    /// Quitte le jeu en sauvegardant la partie en cours
+    pub fn quit(&mut self) {
+        if self.phase == TuiPhase::Game {
+            self.current_player.save_from(&self.engine);
+        }
+        self.set_phase(TuiPhase::Quit);
+    }
+
+    /// Applique une commande au moteur de jeu, comme un clic sur un bouton de l'interface Bevy.
+    /// La sauvegarde du slot est mise à jour quand la progression du joueur change.
+    ///
+    /// # Arguments
+    /// * `command` - La commande à appliquer.
+    pub fn apply_game_command(&mut self, command: GameCommand) -> Vec<GameEvent> {
+        let events = self.engine.apply(command);
+
+        if events.iter().any(GameEvent::requires_save) {
+            self.current_player.save_from(&self.engine);
+        }
+        if events
+            .iter()
+            .any(|event| matches!(event, GameEvent::ScreenChanged(_)))
+        {
+            self.selected = 0;
+        }
+        events
+    }
+
+    /// Lance la partie avec le joueur du slot, ou sans joueur si aucun slot n'est donné
+    ///
+    /// # Arguments
+    /// * `slot` - Le slot de sauvegarde à charger.
+    pub fn start_game(&mut self, slot: Option<usize>) {
+        self.current_player.slot = slot;
+        self.current_player.save_dir = self.save_dir.clone();
+        self.current_player.save =
+            match slot.map(|slot| JsonLoader::load_save_game(&self.save_dir.slot_path(slot))) {
+                Some(Ok(save)) => Some(save),
+                Some(Err(e)) => {
+                    self.message = Some(format!("Erreur lors du chargement du joueur: {}", e));
+                    None
+                }
+                None => None,
+            };
+        self.engine =
+            GameEngine::from_content(&self.content, self.current_player.save.as_ref(), self.seed);
+        self.set_phase(TuiPhase::Game);
+    }
+
+    /// Lance la partie directement dans le combat d'une arène, pour les tests
+    ///
+    /// # Arguments
+    /// * `slot` - Le slot de sauvegarde à charger.
+    /// * `arena_id` - L'id de l'arène dans `arena.json`.
+    pub fn start_arena(&mut self, slot: Option<usize>, arena_id: &str) {
+        self.start_game(slot);
+        if self.engine.start_arena(arena_id).is_empty() {
+            self.message = Some(format!("Arene inconnue : {}", arena_id));
+        }
+    }
+
+    /// Lance la partie comme demandé en ligne de commande : dans le combat d'une arène,
+    /// avec le joueur d'un slot, ou au menu principal si rien n'est demandé
+    ///
+    /// # Arguments
+    /// * `slot` - Le slot de sauvegarde à charger.
+    /// * `arena_id` - L'id de l'arène de départ.
+    pub fn start_with(&mut self, slot: Option<usize>, arena_id: Option<&str>) {
+        match arena_id {
+            Some(arena_id) => self.start_arena(slot, arena_id),
+            None if slot.is_some() => self.start_game(slot),
+            None => {}
+        }
+    }
+
+    /// Enregistre la session dans un fichier de rejeu : la graine et les slots de départ,
+    /// puis chaque entrée du joueur. Sans graine donnée, une graine est tirée pour toute la session.
+    /// À appeler avant `start_with`, avec le même slot et la même arène.
+    ///
+    /// # Arguments
+    /// * `path` - Le fichier de rejeu.
+    /// * `slot` - Le slot chargé directement au lancement.
+    /// * `arena_id` - L'arène de départ.
+    pub fn record_replay(&mut self, path: String, slot: Option<usize>, arena_id: Option<String>) {
+        let header = ReplayHeader::capture::<TuiInput>(&self.save_dir, self.seed, slot, arena_id);
+        match ReplayRecorder::create(path, &header) {
+            Ok(recorder) => {
+                self.seed = Some(header.seed);
+                self.recorder = Some(recorder);
+            }
+            Err(e) => self.message = Some(format!("Impossible d'enregistrer le rejeu: {}", e)),
+        }
+    }
+
+    // Déclenche l'action d'un choix du menu
+    fn activate(&mut self, action: TuiAction) {
+        self.message = None;
+        match action {
+            TuiAction::NewGame => self.open_slots(false),
+            TuiAction::LoadGame => self.open_slots(true),
+            TuiAction::Quit => self.quit(),
+            TuiAction::BackToMenu => self.go_to_menu(),
+            TuiAction::SelectSlot(slot) => self.select_slot(slot),
+            TuiAction::RestoreSlot(slot) => {
+                let file_path = self.save_dir.slot_path(slot);
+                self.message = Some(match JsonLoader::restore_latest_backup(&file_path) {
+                    Ok(save) => format!(
+                        "Sauvegarde de {} restauree dans {}",
+                        save.player.caracter.name, file_path
+                    ),
+                    Err(e) => format!("Erreur lors de la restauration du slot: {}", e),
+                });
+                self.slot_info = SlotInfo::load(&self.save_dir);
+            }
+            TuiAction::EditName => self.editing = Some(CreationField::Name),
+            TuiAction::EditStyle => self.editing = Some(CreationField::Style),
+            TuiAction::ToggleBadge(index) => {
+                self.creation_data.selected_badge_index =
+                    if self.creation_data.selected_badge_index == Some(index) {
+                        None
+                    } else {
+                        Some(index)
+                    };
+            }
+            TuiAction::ToggleAptitude(index) => {
+                let picks = aptitude_picks(&self.content);
+                toggle_aptitude(&mut self.creation_data.selected_aptitudes, index, picks);
+            }
+            TuiAction::CreatePlayer => self.confirm_creation(),
+            TuiAction::Game(command) => {
+                self.apply_game_command(command);
+            }
+        }
+    }
+
+    // Retour à l'étape précédente avec Échap
+    fn go_back(&mut self) {
+        match self.phase {
+            TuiPhase::Menu | TuiPhase::LoadErrors => self.set_phase(TuiPhase::Quit),
+            TuiPhase::Slots { .. } | TuiPhase::Creation => self.go_to_menu(),
+            TuiPhase::Game if self.engine.current_screen == GameScreenType::Main => {
+                self.go_to_menu()
+            }
+            TuiPhase::Game => {
+                self.apply_game_command(GameCommand::BackToMain);
+            }
+            TuiPhase::Quit => {}
+        }
+    }
+
+    // Revient au menu principal, en sauvegardant la partie en cours
+    fn go_to_menu(&mut self) {
+        if self.phase == TuiPhase::Game {
+            self.current_player.save_from(&self.engine);
+            self.current_player = CurrentPlayer::default();
+        }
+        self.editing = None;
+        self.set_phase(TuiPhase::Menu);
+    }
+
+    // Change d'étape et remet la sélection sur le premier choix
+    fn set_phase(&mut self, phase: TuiPhase) {
+        self.phase = phase;
+        self.selected = 0;
+    }
+
+    // Affiche les slots de sauvegarde, pour une nouvelle partie ou un chargement
+    fn open_slots(&mut self, load_game: bool) {
+        self.slot_info = SlotInfo::load(&self.save_dir);
+        self.set_phase(TuiPhase::Slots { load_game });
+    }
+
+    // Choix d'un slot : création d'un personnage ou chargement de la partie
+    fn select_slot(&mut self, slot: usize) {
+        let TuiPhase::Slots { load_game } = self.phase else {
+            return;
+        };
+        if !load_game {
+            self.selected_slot = Some(slot);
+            self.creation_data = PlayerCreationData::default();
+            self.set_phase(TuiPhase::Creation);
+        } else if self.slot_info.info[slot].is_some() {
+            self.start_game(Some(slot));
+        } else {
+            self.message = Some("Ce slot est vide.".to_string());
+        }
+    }
+
+    // Crée le personnage, l'écrit dans le slot choisi et lance la partie
+    fn confirm_creation(&mut self) {
+        let player = match create_player(&self.creation_data, &self.content) {
+            Ok(player) => player,
+            Err(message) => {
+                self.message = Some(message);
+                return;
+            }
+        };
+
+        let slot = self.selected_slot.unwrap_or(0);
+        JsonLoader::ensure_save_directory(&self.save_dir.0);
+        match JsonLoader::save_game(&self.save_dir.slot_path(slot), &SaveGame::new(player)) {
+            Ok(_) => self.start_game(Some(slot)),
+            Err(e) => self.message = Some(format!("Erreur lors de la sauvegarde du joueur: {}", e)),
+        }
+    }
+
+    // Modifie le texte du champ de saisie affiché
+    fn edit_input(&mut self, text: String) {
+        match self.editing {
+            Some(CreationField::Name) => self.creation_data.name = text,
+            Some(CreationField::Style) => self.creation_data.style = text,
+            None if self.phase == TuiPhase::Game => {
+                self.apply_game_command(GameCommand::TypeAnswer(text));
+            }
+            None => {}
+        }
+    }
+
+    // Erreurs de chargement du contenu du jeu
+    fn load_errors_view(&self) -> TuiView {
+        let mut view = TuiView::new("Impossible de charger les donnees du jeu");
+        for error in &self.load_errors {
+            view.line(error.to_string());
+        }
+        view.choice(TuiChoice::new("Retour", TuiAction::BackToMenu));
+        view.choice(TuiChoice::new("Quitter", TuiAction::Quit));
+        view
+    }
+
+    // Les 3 slots de sauvegarde
+    fn slots_view(&self, load_game: bool) -> TuiView {
+        let mut view = TuiView::new(if load_game {
+            "Choisissez une sauvegarde a charger"
+        } else {
+            "Choisissez un slot pour la nouvelle partie"
+        });
+        for (i, name) in self.slot_info.info.iter().enumerate() {
+            let slot_text = match name {
+                Some(name) => name.clone(),
+                None if self.slot_info.restorable[i] => "Illisible".to_string(),
+                None => "Vide".to_string(),
+            };
+            view.choice(TuiChoice::new(
+                format!("Slot {} : {}", i + 1, slot_text),
+                TuiAction::SelectSlot(i),
+            ));
+            if self.slot_info.restorable[i] {
+                view.choice(TuiChoice::new(
+                    format!("Restaurer le slot {}", i + 1),
+                    TuiAction::RestoreSlot(i),
+                ));
+            }
+        }
+        view.choice(TuiChoice::new("Retour", TuiAction::BackToMenu));
+        view
+    }
+
+    // Création de personnage : nom, style, badge de départ et aptitudes
+    fn creation_view(&self) -> TuiView {
+        let data = &self.creation_data;
+        let mut view = TuiView::new(format!(
+            "Creation de personnage - Slot {}",
+            self.selected_slot.unwrap_or(0) + 1
+        ));
+        view.input = match self.editing {
+            Some(CreationField::Name) => Some(data.name.clone()),
+            Some(CreationField::Style) => Some(data.style.clone()),
+            None => None,
+        };
+
+        view.choice(TuiChoice::new(
+            format!("Nom du personnage: {}", data.name),
+            TuiAction::EditName,
+        ));
+        view.choice(TuiChoice::new(
+            format!("Style: {}", data.style),
+            TuiAction::EditStyle,
+        ));
+
+        for (i, badge) in self.content.badges.iter().enumerate() {
+            let checked = data.selected_badge_index == Some(i);
+            view.choice(TuiChoice::new(
+                format!(
+                    "[{}] Badge {} - {}",
+                    if checked { "x" } else { " " },
+                    badge.name,
+                    badge.features.join(", ")
+                ),
+                TuiAction::ToggleBadge(i),
+            ));
+        }
+
+        view.line(format!(
+            "Choisissez {} aptitude(s).",
+            aptitude_picks(&self.content)
+        ));
+        for (i, aptitude) in self.content.aptitudes.iter().enumerate() {
+            let checked = data.selected_aptitudes.contains(&i);
+            view.choice(TuiChoice::new(
+                format!(
+                    "[{}] {} ({} PP) - {}",
+                    if checked { "x" } else { " " },
+                    aptitude.name,
+                    aptitude.pp,
+                    aptitude.description
+                ),
+                TuiAction::ToggleAptitude(i),
+            ));
+        }
+
+        view.choice(TuiChoice::new("Creer personnage", TuiAction::CreatePlayer));
+        view.choice(TuiChoice::new("Retour", TuiAction::BackToMenu));
+        view
+    }
+}
+
+// Menu principal du jeu
+fn menu_view() -> TuiView {
+    let mut view = TuiView::new("RPG Rust - Menu Principal");
+    view.choice(TuiChoice::new("Nouvelle Partie", TuiAction::NewGame));
+    view.choice(TuiChoice::new("Charger Partie", TuiAction::LoadGame));
+    view.choice(TuiChoice::new("Quitter", TuiAction::Quit));
+    view
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::caracter::bouncer::AnswerMode;
+    use crate::services::engine::state::ArenaCombatState;
+
+    /// Interface terminal en jeu, sans slot : rien n'est écrit sur le disque
+    fn tui_in_game() -> TuiApp {
+        let mut app = TuiApp::new(
+            GameContent::load("assets").unwrap(),
+            vec![],
+            SaveDirectory::default(),
+            None,
+        );
+        app.start_game(None);
+        app
+    }
+
+    /// Sélectionne au clavier le premier choix qui correspond et le valide
+    fn choose(app: &mut TuiApp, matches: impl Fn(&TuiAction) -> bool) {
+        let index = app
+            .view()
+            .choices
+            .iter()
+            .position(|choice| matches(&choice.action))
+            .expect("choix introuvable");
+        while app.selected > index {
+            app.handle_key(TuiKey::Up);
+        }
+        while app.selected < index {
+            app.handle_key(TuiKey::Down);
+        }
+        app.handle_key(TuiKey::Enter);
+    }
+
+    fn choose_command(app: &mut TuiApp, command: GameCommand) {
+        choose(app, |action| *action == TuiAction::Game(command.clone()));
+    }
+
+    /// Test d'une arène jouée entièrement au clavier
+    /// Videur, ingrédients et ordre des instructions mènent à l'écran de fin, comme avec Bevy.
+    #[test]
+    fn test_keyboard_plays_an_arena_to_the_end() {
+        let mut app = tui_in_game();
+        choose_command(&mut app, GameCommand::OpenArenaSelection);
+        choose(&mut app, |action| {
+            matches!(action, TuiAction::Game(GameCommand::ChooseArena(_)))
+        });
+        assert_eq!(app.engine.current_screen, GameScreenType::BouncerQuestion);
+
+        match app.engine.answer_mode {
+            AnswerMode::Choices => {
+                let correct_index = app
+                    .engine
+                    .answer_options
+                    .iter()
+                    .position(|option| *option == app.engine.correct_answer)
+                    .unwrap();
+                choose_command(&mut app, GameCommand::AnswerBouncer(correct_index));
+            }
+            AnswerMode::FreeText => {
+                for c in app.engine.correct_answer.clone().chars() {
+                    app.handle_key(TuiKey::Char(c));
+                }
+                app.handle_key(TuiKey::Enter);
+            }
+        }
+        assert_eq!(app.engine.current_screen, GameScreenType::ArenaPresentation);
+
+        choose_command(&mut app, GameCommand::EnterArena);
+        choose_command(&mut app, GameCommand::StartArenaCombat);
+        let recipe = app.engine.master_recipe.clone().unwrap();
+        for item in &recipe.ingredients {
+            choose_command(
+                &mut app,
+                GameCommand::ToggleIngredient(item.ingredient.clone()),
+            );
+        }
+        choose_command(&mut app, GameCommand::ValidateCocktail);
+        choose_command(&mut app, GameCommand::StartFinalCraft);
+        assert_eq!(
+            app.engine.current_screen,
+            GameScreenType::InstructionOrdering
+        );
+
+        for instruction in &recipe.instructions {
+            let (index, _) = app
+                .engine
+                .remaining_instructions()
+                .into_iter()
+                .find(|(_, remaining)| *remaining == instruction)
+                .unwrap();
+            choose_command(&mut app, GameCommand::SelectInstruction(index));
+        }
+        choose_command(&mut app, GameCommand::ValidateInstructionOrder);
+
+        assert_eq!(app.engine.current_screen, GameScreenType::ArenaEnd);
+        assert_eq!(app.engine.arena_combat_state, ArenaCombatState::Victory);
+        assert!(app
+            .view()
+            .lines
+            .contains(&"Bravo ! Vous avez battu le boss !".to_string()));
+    }
+
+    /// Test de la saisie du nom à la création de personnage
+    /// Les caractères tapés remplissent le champ jusqu'à Entrée, puis les numéros redeviennent des raccourcis.
+    #[test]
+    fn test_creation_name_is_typed_in_input_field() {
+        let mut app = TuiApp::new(
+            GameContent::load("assets").unwrap(),
+            vec![],
+            SaveDirectory::default(),
+            None,
+        );
+        app.phase = TuiPhase::Creation;
+
+        app.handle_key(TuiKey::Enter);
+        for c in "Elendil2".chars() {
+            app.handle_key(TuiKey::Char(c));
+        }
+        app.handle_key(TuiKey::Backspace);
+        app.handle_key(TuiKey::Enter);
+        assert_eq!(app.creation_data.name, "Elendil");
+        assert!(app.view().input.is_none());
+
+        app.handle_key(TuiKey::Char('3'));
+        assert_eq!(app.creation_data.selected_badge_index, Some(0));
+    }
+
+    /// Test du retour avec Échap
+    /// Depuis un écran de jeu, Échap revient à l'écran principal puis au menu.
+    #[test]
+    fn test_escape_goes_back_to_menu() {
+        let mut app = tui_in_game();
+        choose_command(&mut app, GameCommand::OpenShop);
+
+        app.handle_key(TuiKey::Esc);
+        assert_eq!(app.engine.current_screen, GameScreenType::Main);
+        app.handle_key(TuiKey::Esc);
+        assert_eq!(app.phase, TuiPhase::Menu);
+        app.handle_key(TuiKey::Esc);
+        assert!(!app.is_running());
+    }
+}
@@ -0,0 +1,534 @@
+use crate::models::caracter::bouncer::AnswerMode;
+use crate::services::engine::state::{ArenaCombatState, GameScreenType};
+use crate::services::engine::{GameCommand, GameEngine};
+
+/// Action déclenchée par un choix du menu dans le terminal
+#[derive(Debug, Clone, PartialEq)]
+pub enum TuiAction {
+    NewGame,
+    LoadGame,
+    Quit,
+    BackToMenu,
+    SelectSlot(usize),
+    RestoreSlot(usize),
+    EditName,
+    EditStyle,
+    ToggleBadge(usize),
+    ToggleAptitude(usize),
+    CreatePlayer,
+    Game(GameCommand),
+}
+
+/// Un choix du menu : le texte affiché et l'action déclenchée
+#[derive(Debug, Clone, PartialEq)]
+pub struct TuiChoice {
+    pub label: String,
+    pub action: TuiAction,
+}
+
+impl TuiChoice {
+    pub fn new(label: impl Into<String>, action: TuiAction) -> Self {
+        Self {
+            label: label.into(),
+            action,
+        }
+    }
+
+    // Choix qui envoie une commande au moteur de jeu
+    fn game(label: impl Into<String>, command: GameCommand) -> Self {
+        Self::new(label, TuiAction::Game(command))
+    }
+}
+
+/// Ce qu'affiche un écran du terminal : un titre, des lignes de texte,
+/// un champ de saisie éventuel et la liste des choix
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TuiView {
+    pub title: String,
+    pub lines: Vec<String>,
+    pub input: Option<String>,
+    pub choices: Vec<TuiChoice>,
+}
+
+impl TuiView {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Ajoute une ligne de texte
+    pub fn line(&mut self, line: impl Into<String>) {
+        self.lines.push(line.into());
+    }
+
+    /// Ajoute un choix au menu
+    pub fn choice(&mut self, choice: TuiChoice) {
+        self.choices.push(choice);
+    }
+}
+
+/// Construit l'écran du terminal correspondant à l'écran courant du moteur de jeu.
+/// Les textes et les choix sont ceux des écrans Bevy, les règles restent celles du moteur.
+///
+/// # Arguments
+/// * `engine` - Le moteur de jeu de la partie en cours.
+pub fn game_view(engine: &GameEngine) -> TuiView {
+    match engine.current_screen {
+        GameScreenType::Main => main_view(engine),
+        GameScreenType::ArenaSelection => arena_selection_view(engine),
+        GameScreenType::BouncerQuestion => bouncer_question_view(engine),
+        GameScreenType::ArenaPresentation => arena_presentation_view(engine),
+        GameScreenType::Arena => arena_combat_view(engine),
+        GameScreenType::InstructionOrdering => instruction_ordering_view(engine),
+        GameScreenType::ArenaEnd => arena_end_view(engine),
+        GameScreenType::Shop => shop_view(engine),
+        GameScreenType::BarService => bar_service_view(engine),
+        GameScreenType::BadgeCase => badge_case_view(engine),
+    }
+}
+
+// Écran principal de la partie
+fn main_view(engine: &GameEngine) -> TuiView {
+    let mut view = TuiView::new("RPG Rust");
+    view.line(format!("Graine : {}", engine.rng.seed()));
+    view.choice(TuiChoice::game(
+        "Selectionner une Arene",
+        GameCommand::OpenArenaSelection,
+    ));
+    view.choice(TuiChoice::game("Servir au bar", GameCommand::OpenBar));
+    view.choice(TuiChoice::game("Boutique", GameCommand::OpenShop));
+    view.choice(TuiChoice::game(
+        "Vitrine des badges",
+        GameCommand::OpenBadgeCase,
+    ));
+    view.choice(TuiChoice::new("Retour", TuiAction::BackToMenu));
+    view
+}
+
+// Liste des arènes visibles, les arènes verrouillées restent affichées
+fn arena_selection_view(engine: &GameEngine) -> TuiView {
+    let mut view = TuiView::new("Choisissez votre Arene");
+    if engine.wrong_answer_message {
+        view.line("Mauvaise reponse ! Vous avez ete expulse de l'entree.");
+    }
+    if let Some(message) = &engine.locked_arena_message {
+        view.line(message.clone());
+    }
+
+    for (index, arena) in engine.arenas.iter().enumerate() {
+        if !engine.is_arena_visible(arena) {
+            continue;
+        }
+        let mut label = format!(
+            "{} - Theme: {} - Difficulte: {}",
+            arena.name, arena.theme, arena.difficulty
+        );
+        if !engine.is_arena_unlocked(arena) {
+            label.push_str(" (Verrouillee)");
+        }
+        view.choice(TuiChoice::game(label, GameCommand::ChooseArena(index)));
+    }
+    view.choice(TuiChoice::game("Retour", GameCommand::BackToMain));
+    view
+}
+
+// Énigme du videur, à choix multiples ou à réponse libre
+fn bouncer_question_view(engine: &GameEngine) -> TuiView {
+    let bouncer_name = engine.bouncer_name.as_deref().unwrap_or("???");
+    let mut view = TuiView::new(match &engine.selected_arena {
+        Some(arena) => format!("Arene: {} - Bouncer: {}", arena, bouncer_name),
+        None => format!("Bouncer: {}", bouncer_name),
+    });
+    view.line(format!("Question: {}", engine.current_question));
+
+    // Les réponses viennent en premier pour que Entrée valide la saisie libre
+    match engine.answer_mode {
+        AnswerMode::FreeText => {
+            view.input = Some(engine.typed_answer.clone());
+            view.choice(TuiChoice::game("Repondre", GameCommand::SubmitTypedAnswer));
+        }
+        AnswerMode::Choices => {
+            for (index, option) in engine.answer_options.iter().enumerate() {
+                view.choice(TuiChoice::game(
+                    option.clone(),
+                    GameCommand::AnswerBouncer(index),
+                ));
+            }
+        }
+    }
+
+    if let Some(hint) = &engine.current_hint {
+        if engine.hint_revealed {
+            view.line(format!("Indice: {}", hint));
+        } else {
+            view.choice(TuiChoice::game("Demander un indice", GameCommand::ShowHint));
+        }
+    }
+    view.choice(TuiChoice::game("Retour", GameCommand::BackToArenaSelection));
+    view
+}
+
+// Présentation du maître de l'arène et de ses attaques
+fn arena_presentation_view(engine: &GameEngine) -> TuiView {
+    let mut view = TuiView::new(match &engine.selected_arena {
+        Some(arena) => format!("Presentation de l'Arene: {}", arena),
+        None => "Presentation de l'Arene".to_string(),
+    });
+    if let Some(master_name) = &engine.master_name {
+        view.line(format!("Maitre de l'Arene: {}", master_name));
+        view.lines.extend(engine.master_dialogs.iter().cloned());
+    }
+    if let Some(master_style) = &engine.master_style {
+        view.line(format!("Style: {}", master_style));
+    }
+    if let Some(master_badge) = &engine.master_badge {
+        view.line(format!("Badge: {}", master_badge));
+    }
+    view.line("Attaques:");
+    for attack in &engine.master_attacks {
+        view.line(format!(
+            "- {} ({} degats, precision {:.0}%)",
+            attack.name,
+            attack.damage,
+            attack.accuracy * 100.0
+        ));
+    }
+    view.choice(TuiChoice::game("Continuer", GameCommand::EnterArena));
+    view
+}
+
+// Combat contre le maître : introduction, choix des ingrédients puis transition vers les instructions
+fn arena_combat_view(engine: &GameEngine) -> TuiView {
+    let master_name = engine.master_name.as_deref().unwrap_or("???");
+
+    if engine.show_intro_screen {
+        let mut view = TuiView::new("Combat d'Arene");
+        view.line(format!(
+            "Vous allez affronter {} sur {}",
+            master_name,
+            engine.selected_arena.as_deref().unwrap_or("???"),
+        ));
+        view.choice(TuiChoice::game(
+            "Commencer le combat",
+            GameCommand::StartArenaCombat,
+        ));
+        view.choice(TuiChoice::game("Retour", GameCommand::BackToMain));
+        return view;
+    }
+
+    if engine.show_crafting_phase {
+        let mut view = TuiView::new("Combat d'Arene");
+        view.line("Bien joue ! Tu as trouve la bonne recette.");
+        view.line("Maintenant concocte le cocktail comme il faut pour finir le boss.");
+        view.choice(TuiChoice::game("Continuer", GameCommand::StartFinalCraft));
+        return view;
+    }
+
+    let mut view = TuiView::new(format!("Combat contre le Maitre: {}", master_name));
+    push_hp_lines(&mut view, engine);
+    if let Some(player) = &engine.player {
+        let next_level = engine
+            .next_level_xp()
+            .map_or("max".to_string(), |xp| xp.to_string());
+        view.line(format!(
+            "Niveau {} - XP {}/{} | Votre bourse: {}",
+            player.level, player.xp, next_level, player.caracter.bankroll
+        ));
+    }
+    push_boss_turn_report(&mut view, engine);
+
+    view.line(format!("Vos PP: {}", engine.player_pp));
+    if let Some(message) = &engine.aptitude_message {
+        view.line(message.clone());
+    }
+    if engine.arena_combat_state == ArenaCombatState::PlayerTurn {
+        for (index, aptitude) in engine.player_aptitudes.iter().enumerate() {
+            view.choice(TuiChoice::game(
+                format!("Aptitude : {} ({} PP)", aptitude.name, aptitude.pp),
+                GameCommand::UseAptitude(index),
+            ));
+        }
+    }
+
+    push_ingredient_choices(&mut view, engine);
+    view.choice(TuiChoice::game(
+        "Valider le Cocktail",
+        GameCommand::ValidateCocktail,
+    ));
+    view.choice(TuiChoice::game("Retour", GameCommand::BackToMain));
+    view
+}
+
+// Remise en ordre des instructions de la recette du maître
+fn instruction_ordering_view(engine: &GameEngine) -> TuiView {
+    let mut view = TuiView::new("Derniere etape : remettre les instructions dans le bon ordre !");
+    push_hp_lines(&mut view, engine);
+    push_boss_turn_report(&mut view, engine);
+
+    let Some(recipe) = &engine.master_recipe else {
+        view.line("Aucune recette disponible.");
+        view.choice(TuiChoice::game("Retour", GameCommand::BackToMain));
+        return view;
+    };
+
+    let items: Vec<String> = recipe.ingredients.iter().map(ToString::to_string).collect();
+    view.line(format!("Recette : {}", items.join(", ")));
+    push_instruction_choices(&mut view, engine);
+
+    if let Some(correct_steps) = engine.current_crafting.correct_steps {
+        view.line(format!(
+            "Ordre incorrect : {} etape(s) sur {} justes au debut. Reessayez.",
+            correct_steps,
+            recipe.instructions.len()
+        ));
+    }
+    // Le moteur refuse une séquence incomplète, la validation n'est proposée qu'une fois complète
+    if engine.current_crafting.selected_steps.len() == recipe.instructions.len() {
+        view.choice(TuiChoice::game("Valider l'ordre", GameCommand::ValidateInstructionOrder));
+    } else {
+        view.line(format!("Selectionnez {} instructions pour valider", recipe.instructions.len()));
+    }
+    view.choice(TuiChoice::game("Retour", GameCommand::BackToMain));
+    view
+}
+
+// Résultat du combat et progression du joueur
+fn arena_end_view(engine: &GameEngine) -> TuiView {
+    let master_name = engine.master_name.as_deref().unwrap_or("???");
+    let arena_name = engine.selected_arena.as_deref().unwrap_or("???");
+
+    let mut view = TuiView::new("Fin du combat");
+    match engine.arena_combat_state {
+        ArenaCombatState::Victory => {
+            view.line("Bravo ! Vous avez battu le boss !");
+            view.line(format!(
+                "Maitre battu : {} - Arene : {}",
+                master_name, arena_name
+            ));
+            if engine.current_crafting.cocktail_ready {
+                view.line("Vous avez parfaitement execute la recette !");
+            } else {
+                view.line("Vous avez trouve la bonne combinaison d'ingredients !");
+            }
+            if engine
+                .selected_arena_data()
+                .is_some_and(|arena| arena.championship)
+            {
+                view.line("Vous etes sacre champion de tous les bars !");
+            }
+            if let Some(player) = &engine.player {
+                view.line(format!(
+                    "Badges gagnes : {} | Reputation : {} | Niveau {} ({} XP)",
+                    player.badges_won.len(),
+                    player.reputation,
+                    player.level,
+                    player.xp
+                ));
+            }
+        }
+        ArenaCombatState::Defeat => {
+            view.line("Defaite ! Vous etes tombe au combat...");
+            view.line(format!(
+                "Vous avez ete vaincu par {} dans l'arene {}.",
+                master_name, arena_name
+            ));
+            view.line("Vos HP sont tombes a zero !");
+        }
+        _ => view.line("Combat termine"),
+    }
+    view.line(format!(
+        "HP finaux - Vous: {} | Boss: {}",
+        engine.player_hp, engine.boss_hp
+    ));
+    view.choice(TuiChoice::game(
+        "Retour a la selection des niveaux",
+        GameCommand::OpenArenaSelection,
+    ));
+    view
+}
+
+// Boutique : choix du marchand puis achat de ses ingrédients
+fn shop_view(engine: &GameEngine) -> TuiView {
+    let mut view = TuiView::new("Boutique");
+    if let Some(player) = &engine.player {
+        view.line(format!(
+            "Votre bourse: {} | Inventaire: {} ingredient(s)",
+            player.caracter.bankroll,
+            player
+                .inventory
+                .iter()
+                .map(|stack| stack.quantity)
+                .sum::<u32>()
+        ));
+    }
+    if let Some(message) = &engine.shop_message {
+        view.line(message.clone());
+    }
+
+    for (index, trader) in engine.traders.iter().enumerate() {
+        view.choice(TuiChoice::game(
+            format!("{} ({})", trader.pnj.caracter.name, trader.pnj.job),
+            GameCommand::ChooseTrader(index),
+        ));
+    }
+
+    if let Some(trader) = engine
+        .selected_trader
+        .and_then(|index| engine.traders.get(index))
+    {
+        if let Some(dialog) = trader.pnj.dialogs.first() {
+            view.line(format!("{}: \"{}\"", trader.pnj.caracter.name, dialog));
+        }
+        for (index, ingredient) in trader.ingredients.iter().enumerate() {
+            view.choice(TuiChoice::game(
+                format!(
+                    "Acheter {} ({}) - {} pieces",
+                    ingredient.name,
+                    ingredient.description,
+                    engine.trader_price(ingredient)
+                ),
+                GameCommand::BuyIngredient(index),
+            ));
+        }
+    }
+    view.choice(TuiChoice::game("Retour", GameCommand::BackToMain));
+    view
+}
+
+// Service au bar : la commande du client, ses ingrédients et ses instructions
+fn bar_service_view(engine: &GameEngine) -> TuiView {
+    let mut view = TuiView::new("Service au bar");
+    if let Some(player) = &engine.player {
+        view.line(format!(
+            "Votre bourse: {} | Reputation: {}",
+            player.caracter.bankroll, player.reputation
+        ));
+    }
+    if let Some(message) = &engine.bar_message {
+        view.line(message.clone());
+    }
+
+    let Some(client) = &engine.current_client else {
+        view.line("Aucun client ce soir.");
+        view.choice(TuiChoice::game("Retour", GameCommand::BackToMain));
+        return view;
+    };
+
+    if let Some(dialog) = client.pnj.dialogs.first() {
+        view.line(format!("{}: \"{}\"", client.pnj.caracter.name, dialog));
+    }
+    let items: Vec<String> = client
+        .order
+        .ingredients
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    view.line(format!(
+        "Commande : {} ({})",
+        client.drink,
+        items.join(", ")
+    ));
+
+    push_ingredient_choices(&mut view, engine);
+    push_instruction_choices(&mut view, engine);
+    view.choice(TuiChoice::game("Servir", GameCommand::ServeOrder));
+    view.choice(TuiChoice::game("Retour", GameCommand::BackToMain));
+    view
+}
+
+// Vitrine des badges de la campagne
+fn badge_case_view(engine: &GameEngine) -> TuiView {
+    let collection = engine.badge_collection();
+    let owned = collection
+        .iter()
+        .filter(|(_, badge)| engine.has_badge(&badge.name))
+        .count();
+
+    let mut view = TuiView::new("Vitrine des badges");
+    view.line(format!("{} / {} badges gagnes", owned, collection.len()));
+    for (arena, badge) in &collection {
+        if engine.has_badge(&badge.name) {
+            view.line(format!("[x] {} - Arene {}", badge.name, arena.name));
+            for effect in &badge.effects {
+                view.line(format!("    {}", effect.label()));
+            }
+        } else {
+            view.line(format!(
+                "[ ] {} - Arene {} (A gagner)",
+                badge.name, arena.name
+            ));
+        }
+    }
+    view.choice(TuiChoice::game("Retour", GameCommand::BackToMain));
+    view
+}
+
+// Points de vie du joueur et du boss
+fn push_hp_lines(view: &mut TuiView, engine: &GameEngine) {
+    view.line(format!(
+        "Votre HP: {} | HP du Boss: {}",
+        engine.player_hp, engine.boss_hp
+    ));
+}
+
+// Dernière attaque du boss et effets de statut actifs du joueur
+fn push_boss_turn_report(view: &mut TuiView, engine: &GameEngine) {
+    if let Some(report) = &engine.current_boss_attack {
+        view.line(report.describe(engine.master_name.as_deref().unwrap_or("Le boss")));
+    }
+    for effect in &engine.player_status_effects {
+        view.line(format!("Statut: {}", effect.label()));
+    }
+}
+
+// Ingrédients proposés avec leur quantité, cochés s'ils sont sélectionnés
+fn push_ingredient_choices(view: &mut TuiView, engine: &GameEngine) {
+    let ingredients = engine.crafting_ingredients();
+    if ingredients.is_empty() {
+        view.line("Votre inventaire est vide : passez a la boutique !");
+    }
+    for (ingredient, count) in ingredients {
+        let selected = engine
+            .current_crafting
+            .selected_ingredients
+            .contains(&ingredient);
+        let mut label = format!("[{}] {}", if selected { "x" } else { " " }, ingredient);
+        if let Some(count) = count {
+            label.push_str(&format!(" x{}", count));
+        }
+        if engine.revealed_ingredients.contains(&ingredient) {
+            label.push_str(" (revele)");
+        }
+        view.choice(TuiChoice::game(
+            label,
+            GameCommand::ToggleIngredient(ingredient),
+        ));
+    }
+}
+
+// Étapes encore disponibles à choisir dans l'ordre, puis la séquence du joueur
+// avec l'annulation de la dernière étape
+fn push_instruction_choices(view: &mut TuiView, engine: &GameEngine) {
+    let selected = engine.selected_instructions();
+    for (index, instruction) in engine.remaining_instructions() {
+        view.choice(TuiChoice::game(
+            format!("Ajouter : {}", instruction),
+            GameCommand::SelectInstruction(index),
+        ));
+    }
+    if selected.is_empty() {
+        view.line("Votre sequence : aucune instruction selectionnee");
+        return;
+    }
+    view.line("Votre sequence :");
+    for (i, instruction) in selected.iter().enumerate() {
+        view.line(format!("{}. {}", i + 1, instruction));
+    }
+    view.choice(TuiChoice::game(
+        "Annuler la derniere etape",
+        GameCommand::UndoInstruction,
+    ));
+    view.choice(TuiChoice::game("Vider", GameCommand::ClearInstructions));
+}
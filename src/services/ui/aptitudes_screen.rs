@@ -1,4 +1,4 @@
-use crate::models::aptitude::Aptitude;
+use crate::services::content::GameContent;
 use crate::services::ui::constants::{ButtonAction, NORMAL_BUTTON};
 use bevy::prelude::*;
 use bevy::ui::{AlignItems, FlexDirection, JustifyContent, UiRect, Val};
@@ -7,18 +7,11 @@ use bevy::ui::{AlignItems, FlexDirection, JustifyContent, UiRect, Val};
 #[derive(Component)]
 pub struct AptitudesScreen;
 
-/// Ressource qui contient la liste des aptitudes à afficher
-#[derive(Resource)]
-pub struct AptitudeList {
-    // Vecteur de toutes les aptitudes disponibles
-    pub aptitudes: Vec<Aptitude>,
-}
-
 /// On crée l'interface d'affichage des aptitudes avec la liste complète
 /// # Arguments
 /// - `commands`: Les commandes pour créer des entités dans Bevy.
-/// - `aptitude_list`: La liste des aptitudes à afficher, passée en tant que ressource.
-pub fn setup_aptitudes_screen(mut commands: Commands, aptitude_list: Res<AptitudeList>) {
+/// - `content`: Le contenu du jeu, qui contient la liste des aptitudes à afficher.
+pub fn setup_aptitudes_screen(mut commands: Commands, content: Res<GameContent>) {
     commands
         .spawn((
             Node {
@@ -36,7 +29,7 @@ pub fn setup_aptitudes_screen(mut commands: Commands, aptitude_list: Res<Aptitud
         .with_children(|parent| {
             // Affichage de chaque aptitude
 
-            for aptitude in &aptitude_list.aptitudes {
+            for aptitude in &content.aptitudes {
                 parent.spawn(Text::from(aptitude.name.clone()));
             }
 
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde_derive::{Deserialize, Serialize};
 
 /// Constantes de couleurs partagees
 pub const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
@@ -8,6 +9,7 @@ pub const GREEN: Color = Color::srgb(0.0, 1.0, 0.0);
 pub const BLUE: Color = Color::srgb(0.0, 0.0, 1.0);
 pub const WHITE: Color = Color::srgb(1.0, 1.0, 1.0);
 pub const BLACK: Color = Color::srgb(0.0, 0.0, 0.0);
+pub const GREY: Color = Color::srgb(0.5, 0.5, 0.5);
 
 /// Etats principaux de l'application
 #[derive(States, Debug, Clone, Eq, PartialEq, Hash, Default)]
@@ -19,17 +21,14 @@ pub enum AppState {
     PlayerSlot,
     PlayerCreation,
     StartScreen,
+    LoadError,
 }
 
-/// Etats du jeu (sous-etats de AppState::Game)
-#[derive(States, Debug, Clone, Eq, PartialEq, Hash, Default)]
-pub enum GameState {
-    #[default]
-    Playing
-}
+/// Filtre des boutons dont l'interaction vient de changer : clic, survol ou fin de survol
+pub type ChangedButton = (Changed<Interaction>, With<Button>);
 
 /// Actions des boutons pour les menus principaux
-#[derive(Component, Clone)]
+#[derive(Component, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ButtonAction {
     NewGame,
     LoadGame,
@@ -37,6 +36,7 @@ pub enum ButtonAction {
     Quit,
     Back,
     SelectSlot(usize),
+    RestoreSlot(usize),
     ConfirmSlot,
     CreatePlayer,
     StartGame,
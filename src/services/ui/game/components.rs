@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde_derive::{Deserialize, Serialize};
 
 #[derive(Component)]
 pub struct GameScreen;
@@ -12,12 +13,14 @@ pub struct ArenaUI;
 #[derive(Component)]
 pub struct ArenaPresentationUI;
 
-#[derive(Component, Clone)]
+#[derive(Component, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum GameButtonAction {
     SelectArena,
     ChooseArena(usize),
     EncounterBouncer,
     AnswerQuestion(usize),
+    SubmitTypedAnswer,
+    ShowHint,
     BackToMainGame,
     BackToArenaSelection,
     SelectIngredient(String),
@@ -25,8 +28,16 @@ pub enum GameButtonAction {
     StartArenaCombat,
     BackToMainFromCombat,
     StartCombat,
+    UseAptitude(usize),
     StartFinalCraft,
-    SelectInstruction(String),
+    SelectInstruction(usize),
     ValidateInstructionOrder,
-    ClearInstructions
+    UndoInstruction,
+    ClearInstructions,
+    OpenShop,
+    ChooseTrader(usize),
+    BuyIngredient(usize),
+    OpenBar,
+    ServeOrder,
+    OpenBadgeCase,
 }
\ No newline at end of file
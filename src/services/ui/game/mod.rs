@@ -6,44 +6,70 @@ pub mod systems;
 // Exports publics pour maintenir la compatibilité
 pub use components::*;
 pub use state::*;
-pub use systems::handle_game_button_actions;
-pub use screens::main_screen::spawn_main_game_screen;
+pub use systems::{handle_game_button_actions, handle_typed_answer_input};
+use systems::button_handler::spawn_current_screen;
 
 // Fonctions principales exposées
 use bevy::prelude::*;
+use crate::services::engine::GameEngine;
+use crate::services::content::GameContent;
 use crate::services::json_loader::JsonLoader;
+use crate::services::ui::player_slot_screen::SelectedPlayerSlot;
 
-pub fn setup_game(mut commands: Commands, mut game_state: ResMut<GameScreenState>) {
-    // Initialise l'état
-    game_state.current_screen = GameScreenType::Main;
-    
-    // Charge les arènes depuis le JSON
-    if let Ok(arenas) = JsonLoader::load_json_arena("assets/caracters/arena.json") {
-        game_state.available_arenas = arenas.iter()
-            .map(|arena| (arena.name.clone(), arena.theme.clone()))
-            .collect();
-    }
-    
-    // Charge les données du bouncer
-    if let Ok(bouncers) = JsonLoader::load_json_bouncers("assets/caracters/pnj/bouncer.json") {
-        if let Some(bouncer) = bouncers.first() {
-            let question = bouncer.enigmas.first()
-                .unwrap_or(&"Question par defaut".to_string())
-                .clone();
-            let options = vec![
-                "L'homme".to_string(),
-                "Un animal".to_string(),
-                "Une machine".to_string(),
-                "Le temps".to_string(),
-            ];
-            
-            game_state.current_question = question;
-            game_state.answer_options = options;
-            game_state.correct_answer = "L'homme".to_string();
+/// Charge le joueur du slot sélectionné dans la ressource `CurrentPlayer`
+pub fn load_current_player(
+    mut current_player: ResMut<CurrentPlayer>,
+    selected_slot: Res<SelectedPlayerSlot>,
+    save_dir: Res<SaveDirectory>,
+) {
+    current_player.slot = selected_slot.slot;
+    current_player.save_dir = save_dir.clone();
+    current_player.save = selected_slot.slot.and_then(|slot| {
+        JsonLoader::load_save_game(&save_dir.slot_path(slot))
+            .map_err(|e| println!("Erreur lors du chargement du joueur: {}", e))
+            .ok()
+    });
+}
+
+pub fn setup_game(
+    mut commands: Commands,
+    mut game_state: ResMut<GameScreenState>,
+    content: Res<GameContent>,
+    current_player: Res<CurrentPlayer>,
+    mut start_arena: ResMut<StartArena>,
+    seed: Res<GameSeed>,
+) {
+    // Initialise le moteur de jeu avec le contenu chargé au démarrage, le joueur du slot
+    // et la graine du hasard
+    game_state.0 = GameEngine::from_content(&content, current_player.save.as_ref(), seed.0);
+
+    // Arène demandée en ligne de commande : on entre directement dans son combat
+    if let Some(arena_id) = start_arena.0.take() {
+        if game_state.start_arena(&arena_id).is_empty() {
+            println!("Arene inconnue : {}", arena_id);
         }
     }
 
-    spawn_main_game_screen(&mut commands);
+    spawn_current_screen(&mut commands, &game_state);
+}
+
+/// Sauvegarde le joueur quand il quitte le jeu
+pub fn save_current_player(
+    mut current_player: ResMut<CurrentPlayer>,
+    game_state: Res<GameScreenState>,
+) {
+    current_player.save_from(&game_state);
+}
+
+/// Sauvegarde le joueur si la fenêtre est fermée pendant la partie
+pub fn save_current_player_on_exit(
+    mut exit_events: EventReader<AppExit>,
+    current_player: ResMut<CurrentPlayer>,
+    game_state: Res<GameScreenState>,
+) {
+    if exit_events.read().next().is_some() {
+        save_current_player(current_player, game_state);
+    }
 }
 
 pub fn despawn_game(mut commands: Commands, query: Query<Entity, With<GameScreen>>) {
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use bevy::ui::{Val, JustifyContent, AlignItems, FlexDirection, UiRect};
-use crate::services::ui::constants::NORMAL_BUTTON;
+use crate::services::ui::constants::{GREEN, GREY, NORMAL_BUTTON, RED, WHITE};
 use crate::services::ui::game::{GameScreen, GameButtonAction, GameScreenState, ArenaUI};
 use std::collections::HashSet;
 
@@ -128,6 +128,21 @@ fn spawn_combat_content(parent: &mut ChildBuilder, game_state: &GameScreenState)
     // HP
     parent.spawn(Text::new(format!("Votre HP: {}", game_state.player_hp)));
     parent.spawn(Text::new(format!("HP du Boss: {}", game_state.boss_hp)));
+    if let Some(player) = &game_state.player {
+        let next_level = game_state
+            .next_level_xp()
+            .map_or("max".to_string(), |xp| xp.to_string());
+        parent.spawn(Text::new(format!(
+            "Niveau {} - XP {}/{} | Votre bourse: {}",
+            player.level, player.xp, next_level, player.caracter.bankroll
+        )));
+    }
+
+    // Dernière attaque du boss
+    spawn_boss_turn_report(parent, game_state);
+
+    // Barre d'aptitudes
+    spawn_aptitude_bar(parent, game_state);
 
     // Section des ingrédients
     spawn_ingredient_selection(parent, game_state);
@@ -155,35 +170,40 @@ fn spawn_combat_content(parent: &mut ChildBuilder, game_state: &GameScreenState)
         .with_child(Text::new("Retour"));
 }
 
-fn spawn_ingredient_selection(parent: &mut ChildBuilder, game_state: &GameScreenState) {
+/// Affiche les ingrédients de l'inventaire du joueur avec leur quantité.
+/// Chaque cocktail validé consomme les ingrédients versés, bons ou mauvais.
+/// # Arguments
+/// - `parent`: Le parent dans lequel la liste sera ajoutée.
+/// - `game_state`: L'état du jeu contenant le joueur et la sélection en cours.
+pub fn spawn_ingredient_selection(parent: &mut ChildBuilder, game_state: &GameScreenState) {
     parent.spawn(Text::new("Selectionnez les ingredients pour le cocktail:"));
 
-    let static_ingredients = vec![
-        "Jus de citron",
-        "Vodka",
-        "Rhum",
-        "Menthe",
-        "Sirop de sucre",
-        "Eau gazeuse",
-        "Tequila",
-        "Triple sec",
-    ];
-
-    let mut all_ingredients_set: HashSet<String> = static_ingredients
-        .into_iter()
-        .map(|s| s.to_string())
-        .collect();
-
-    if let Some(recipe) = &game_state.master_recipe {
-        for ingredient in &recipe.ingredients {
-            all_ingredients_set.insert(ingredient.name.clone());
-        }
+    let ingredients = game_state.crafting_ingredients();
+    if ingredients.is_empty() {
+        parent.spawn((
+            Text::new("Votre inventaire est vide : passez a la boutique !"),
+            TextColor(RED),
+        ));
     }
 
-    let mut all_ingredients: Vec<String> = all_ingredients_set.into_iter().collect();
-    all_ingredients.sort();
+    for (ingredient, count) in ingredients {
+        let selected = game_state
+            .current_crafting
+            .selected_ingredients
+            .contains(&ingredient);
+        // Plus aucun exemplaire : l'ingrédient reste visible mais ne peut plus être choisi
+        let text_color = if count == Some(0) && !selected {
+            GREY
+        } else if game_state.revealed_ingredients.contains(&ingredient) {
+            GREEN
+        } else {
+            WHITE
+        };
+        let label = match count {
+            Some(count) => format!("{} x{}", ingredient, count),
+            None => ingredient.clone(),
+        };
 
-    for ingredient in all_ingredients {
         parent
             .spawn((
                 Button,
@@ -198,9 +218,9 @@ fn spawn_ingredient_selection(parent: &mut ChildBuilder, game_state: &GameScreen
                 BorderColor(Color::BLACK),
                 BorderRadius::MAX,
                 BackgroundColor(NORMAL_BUTTON),
-                GameButtonAction::SelectIngredient(ingredient.to_string()),
+                GameButtonAction::SelectIngredient(ingredient),
             ))
-            .with_child(Text::new(ingredient));
+            .with_child((Text::new(label), TextColor(text_color)));
     }
 
     parent.spawn(Text::new(format!(
@@ -209,6 +229,70 @@ fn spawn_ingredient_selection(parent: &mut ChildBuilder, game_state: &GameScreen
     )));
 }
 
+/// Affiche les aptitudes du joueur avec leur coût en PP.
+/// Chaque aptitude utilisée dépense des PP et laisse le boss riposter.
+/// # Arguments
+/// - `parent`: Le parent dans lequel la barre sera ajoutée.
+/// - `game_state`: L'état du jeu contenant les aptitudes et les PP du joueur.
+fn spawn_aptitude_bar(parent: &mut ChildBuilder, game_state: &GameScreenState) {
+    parent.spawn(Text::new(format!("Vos PP: {}", game_state.player_pp)));
+
+    parent
+        .spawn(Node {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::Center,
+            margin: UiRect::vertical(Val::Px(5.0)),
+            ..Default::default()
+        })
+        .with_children(|bar| {
+            for (index, aptitude) in game_state.player_aptitudes.iter().enumerate() {
+                let affordable = aptitude.effect.is_some() && game_state.player_pp >= aptitude.pp;
+                bar.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(220.0),
+                        height: Val::Px(40.0),
+                        margin: UiRect::all(Val::Px(5.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    BackgroundColor(NORMAL_BUTTON),
+                    GameButtonAction::UseAptitude(index),
+                ))
+                .with_child((
+                    Text::new(format!("{} ({} PP)", aptitude.name, aptitude.pp)),
+                    TextColor(if affordable { WHITE } else { GREY }),
+                ));
+            }
+        });
+
+    if let Some(message) = &game_state.aptitude_message {
+        parent.spawn(Text::new(message.clone()));
+    }
+}
+
+/// Affiche ce que le boss a fait à son dernier tour et les effets de statut actifs du joueur.
+/// # Arguments
+/// - `parent`: Le parent dans lequel le rapport sera ajouté.
+/// - `game_state`: L'état du jeu contenant la dernière attaque du boss.
+pub fn spawn_boss_turn_report(parent: &mut ChildBuilder, game_state: &GameScreenState) {
+    if let Some(report) = &game_state.current_boss_attack {
+        let master_name = game_state.master_name.as_deref().unwrap_or("Le boss");
+        parent.spawn((
+            Text::new(report.describe(master_name)),
+            TextColor(if report.hit { RED } else { WHITE }),
+        ));
+    }
+
+    for effect in &game_state.player_status_effects {
+        parent.spawn(Text::new(format!("Statut: {}", effect.label())));
+    }
+}
+
 /// Affiche le bouton de validation du cocktail et son état.
 /// # Arguments
 /// - `parent`: Le parent dans lequel le bouton sera ajouté.
@@ -233,7 +317,7 @@ fn spawn_cocktail_validation(parent: &mut ChildBuilder, game_state: &GameScreenS
 
     if let Some(recipe) = &game_state.master_recipe {
         let selected = &game_state.current_crafting.selected_ingredients;
-        let expected: HashSet<String> = recipe.ingredients.iter().map(|i| i.name.clone()).collect();
+        let expected: HashSet<String> = recipe.ingredients.iter().map(|item| item.ingredient.clone()).collect();
 
         let correct_count = selected.iter().filter(|i| expected.contains(*i)).count();
         let incorrect_count = selected.len() - correct_count;
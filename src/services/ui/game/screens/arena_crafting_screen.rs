@@ -2,12 +2,12 @@ use bevy::prelude::*;
 use bevy::ui::{Val, JustifyContent, AlignItems, FlexDirection, UiRect};
 use crate::services::ui::constants::NORMAL_BUTTON;
 use crate::services::ui::game::{GameScreen, GameButtonAction, GameScreenState, ArenaUI};
-use rand::seq::SliceRandom;
-use rand::rng;
+use crate::services::ui::game::screens::spawn_boss_turn_report;
 
 /// Affiche l'ecran de phase de crafting de l'Arène.
 /// Cet ecran permet au joueur de reorganiser les instructions d'une recette pour vaincre le boss de l'Arène.
-/// Il affiche les instructions dans un ordre aleatoire et permet de valider l'ordre choisi.
+/// Il affiche les instructions encore disponibles dans l'ordre melange par le moteur, la sequence
+/// choisie par le joueur dans une colonne a part, et permet d'annuler la derniere etape ou de valider.
 /// # Arguments
 /// - `commands`: Les commandes pour creer des entites dans Bevy.
 /// - `game_state`: L'etat du jeu contenant les informations necessaires pour l'ecran de crafting.
@@ -32,92 +32,99 @@ pub fn spawn_arena_crafting_phase_screen(commands: &mut Commands, game_state: &G
         // Afficher les HP
         parent.spawn(Text::new(format!("Votre HP: {}", game_state.player_hp)));
         parent.spawn(Text::new(format!("HP du Boss: {}", game_state.boss_hp)));
+        spawn_boss_turn_report(parent, game_state);
 
         if let Some(recipe) = &game_state.master_recipe {
-            let mut shuffled_instructions = recipe.instructions.clone();
-            shuffled_instructions.shuffle(&mut rng());
+            // Le cocktail est trouvé : on peut afficher les quantités de la recette
+            let items: Vec<String> = recipe.ingredients.iter().map(ToString::to_string).collect();
+            parent.spawn(Text::new(format!("Recette : {}", items.join(", "))));
 
             parent.spawn(Text::new("Cliquez sur les etapes dans l'ordre :"));
 
-            for (index, instruction) in shuffled_instructions.iter().enumerate() {
-                parent
-                    .spawn((
-                        Button,
-                        Node {
-                            width: Val::Px(500.0),
-                            height: Val::Px(40.0),
-                            margin: UiRect::all(Val::Px(5.0)),
-                            justify_content: JustifyContent::Center,
-                            align_items: AlignItems::Center,
-                            ..Default::default()
-                        },
-                        BorderColor(Color::BLACK),
-                        BorderRadius::MAX,
-                        BackgroundColor(NORMAL_BUTTON),
-                        GameButtonAction::SelectInstruction(instruction.clone()),
-                    ))
-                    .with_child(Text::new(format!("Etape {} : {}", index + 1, instruction)));
-            }
-
-            // Afficher l'ordre selectionne avec plus de clarte
-            if game_state.current_crafting.selected_instructions.is_empty() {
-                parent.spawn(Text::new("Aucune instruction selectionnee"));
-            } else {
-                parent.spawn(Text::new("Ordre selectionne :"));
-                for (i, instruction) in game_state.current_crafting.selected_instructions.iter().enumerate() {
-                    parent.spawn(Text::new(format!("{}. {}", i + 1, instruction)));
-                }
-            }
-
-            // Bouton de validation (actif seulement si on a selectionne des instructions)
-            let validation_button_text = if game_state.current_crafting.selected_instructions.len() == recipe.instructions.len() {
-                "Valider l'ordre".to_string()
-            } else {
-                format!("Sélectionnez {} instructions", recipe.instructions.len())
-            };
-
+            // Deux colonnes : les étapes encore disponibles et la séquence du joueur
+            let selected = game_state.selected_instructions();
             parent
-                .spawn((
-                    Button,
-                    Node {
-                        width: Val::Px(300.0),
-                        height: Val::Px(50.0),
-                        margin: UiRect::all(Val::Px(10.0)),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        ..Default::default()
-                    },
-                    GameButtonAction::ValidateInstructionOrder,
-                    BorderColor(Color::BLACK),
-                    BorderRadius::MAX,
-                    BackgroundColor(NORMAL_BUTTON),
-                ))
-                .with_child(Text::new(validation_button_text));
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::FlexStart,
+                    column_gap: Val::Px(30.0),
+                    ..Default::default()
+                })
+                .with_children(|columns| {
+                    columns
+                        .spawn(crafting_column())
+                        .with_children(|column| {
+                            column.spawn(Text::new("Etapes disponibles :"));
+                            for (index, instruction) in game_state.remaining_instructions() {
+                                spawn_crafting_button(
+                                    column,
+                                    instruction,
+                                    GameButtonAction::SelectInstruction(index),
+                                    NORMAL_BUTTON,
+                                );
+                            }
+                        });
+
+                    columns
+                        .spawn(crafting_column())
+                        .with_children(|column| {
+                            column.spawn(Text::new("Votre sequence :"));
+                            if selected.is_empty() {
+                                column.spawn(Text::new("Aucune instruction selectionnee"));
+                                return;
+                            }
+                            for (i, instruction) in selected.iter().enumerate() {
+                                column.spawn(Text::new(format!("{}. {}", i + 1, instruction)));
+                            }
+                            spawn_crafting_button(
+                                column,
+                                "Annuler la derniere etape",
+                                GameButtonAction::UndoInstruction,
+                                NORMAL_BUTTON,
+                            );
+                            spawn_crafting_button(
+                                column,
+                                "Vider",
+                                GameButtonAction::ClearInstructions,
+                                Color::srgb(0.6, 0.3, 0.3),
+                            );
+                        });
+                });
 
-            // Bouton pour vider la selection
-            if !game_state.current_crafting.selected_instructions.is_empty() {
+            // Bouton de validation, affiché seulement quand toutes les étapes sont placées :
+            // le moteur refuse une séquence incomplète
+            if selected.len() == recipe.instructions.len() {
                 parent
                     .spawn((
                         Button,
                         Node {
-                            width: Val::Px(150.0),
-                            height: Val::Px(40.0),
-                            margin: UiRect::all(Val::Px(5.0)),
+                            width: Val::Px(300.0),
+                            height: Val::Px(50.0),
+                            margin: UiRect::all(Val::Px(10.0)),
                             justify_content: JustifyContent::Center,
                             align_items: AlignItems::Center,
                             ..Default::default()
                         },
-                        GameButtonAction::ClearInstructions,
+                        GameButtonAction::ValidateInstructionOrder,
                         BorderColor(Color::BLACK),
                         BorderRadius::MAX,
-                        BackgroundColor(Color::srgb(0.6, 0.3, 0.3)),
+                        BackgroundColor(NORMAL_BUTTON),
                     ))
-                    .with_child(Text::new("Vider"));
+                    .with_child(Text::new("Valider l'ordre"));
+            } else {
+                parent.spawn(Text::new(format!(
+                    "Sélectionnez {} instructions pour valider",
+                    recipe.instructions.len()
+                )));
             }
 
-            // Feedback sur la dernière tentative
-            if !game_state.current_crafting.instruction_correct && game_state.current_crafting.selected_instructions.is_empty() {
-                parent.spawn(Text::new("Ordre incorrect ! Reessayez."));
+            // Feedback sur la dernière tentative : les premières étapes justes
+            if let Some(correct_steps) = game_state.current_crafting.correct_steps {
+                parent.spawn(Text::new(format!(
+                    "Ordre incorrect : {} etape(s) sur {} justes au debut. Reessayez.",
+                    correct_steps,
+                    recipe.instructions.len()
+                )));
             }
         } else {
             parent.spawn(Text::new("Aucune recette disponible."));
@@ -142,4 +149,34 @@ pub fn spawn_arena_crafting_phase_screen(commands: &mut Commands, game_state: &G
             ))
             .with_child(Text::new("Retour"));
     });
-}
\ No newline at end of file
+}
+
+// Colonne de l'écran de crafting
+fn crafting_column() -> Node {
+    Node {
+        flex_direction: FlexDirection::Column,
+        align_items: AlignItems::Center,
+        ..Default::default()
+    }
+}
+
+// Bouton d'une colonne de l'écran de crafting
+fn spawn_crafting_button(parent: &mut ChildBuilder, label: &str, action: GameButtonAction, color: Color) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(400.0),
+                height: Val::Px(40.0),
+                margin: UiRect::all(Val::Px(5.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BorderColor(Color::BLACK),
+            BorderRadius::MAX,
+            BackgroundColor(color),
+            action,
+        ))
+        .with_child(Text::new(label));
+}
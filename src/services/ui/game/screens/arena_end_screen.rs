@@ -53,6 +53,22 @@ pub fn spawn_arena_end_screen(commands: &mut Commands, game_state: &GameScreenSt
             } else {
                 parent.spawn(Text::new("Vous avez trouve la bonne combinaison d'ingredients !"));
             }
+
+            // Fin de la campagne : le championnat demande tous les badges
+            if game_state.selected_arena_data().is_some_and(|arena| arena.championship) {
+                parent.spawn(Text::new("Vous etes sacre champion de tous les bars !"));
+            }
+
+            // Progression du joueur sauvegardée dans son slot
+            if let Some(player) = &game_state.player {
+                parent.spawn(Text::new(format!(
+                    "Badges gagnes : {} | Reputation : {} | Niveau {} ({} XP)",
+                    player.badges_won.len(),
+                    player.reputation,
+                    player.level,
+                    player.xp
+                )));
+            }
         } else if player_lost {
             parent.spawn(Text::new("Defaite ! Vous etes tombe au combat..."));
             
@@ -46,7 +46,12 @@ pub fn spawn_arena_presentation_screen(commands: &mut Commands, game_state: &Gam
 
         parent.spawn(Text::new("Attaques:"));
         for attack in &game_state.master_attacks {
-            parent.spawn(Text::new(format!("- {}", attack)));
+            parent.spawn(Text::new(format!(
+                "- {} ({} degats, precision {:.0}%)",
+                attack.name,
+                attack.damage,
+                attack.accuracy * 100.0
+            )));
         }
 
         // Bouton pour continuer vers l'Arene
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use bevy::ui::{Val, JustifyContent, AlignItems, FlexDirection, UiRect, FlexWrap};
-use crate::services::ui::constants::NORMAL_BUTTON;
+use crate::services::ui::constants::{GREY, NORMAL_BUTTON, WHITE};
 use crate::services::ui::game::{GameScreen, GameButtonAction, GameScreenState};
 
 /// Affiche l'écran de sélection de l'arène.
@@ -37,6 +37,17 @@ pub fn spawn_arena_selection_screen(commands: &mut Commands, game_state: &GameSc
             ));
         }
         
+        // Message si l'arène choisie est verrouillée
+        if let Some(message) = &game_state.locked_arena_message {
+            parent.spawn((
+                Text::new(message.clone()),
+                Node {
+                    margin: UiRect::all(Val::Px(10.0)),
+                    ..Default::default()
+                },
+            ));
+        }
+        
         // Description
         parent.spawn(Text::new("Selectionnez l'arene dans laquelle vous souhaitez vous battre"));
         
@@ -54,8 +65,13 @@ pub fn spawn_arena_selection_screen(commands: &mut Commands, game_state: &GameSc
             }
         )
         .with_children(|arenas_container| {
-            // Créer un bouton pour chaque arène
-            for (index, (arena_name, arena_theme)) in game_state.available_arenas.iter().enumerate() {
+            // Créer un bouton pour chaque arène visible, les arènes verrouillées sont grisées
+            for (index, arena) in game_state.arenas.iter().enumerate() {
+                if !game_state.is_arena_visible(arena) {
+                    continue;
+                }
+                let unlocked = game_state.is_arena_unlocked(arena);
+
                 arenas_container
                     .spawn((
                         Button,
@@ -75,8 +91,16 @@ pub fn spawn_arena_selection_screen(commands: &mut Commands, game_state: &GameSc
                         GameButtonAction::ChooseArena(index),
                     ))
                     .with_children(|button| {
-                        button.spawn(Text::new(arena_name.clone()));
-                        button.spawn(Text::new(format!("Theme: {}", arena_theme)));
+                        let text_color = if unlocked { WHITE } else { GREY };
+                        button.spawn((Text::new(arena.name.clone()), TextColor(text_color)));
+                        button.spawn((Text::new(format!("Theme: {}", arena.theme)), TextColor(text_color)));
+                        button.spawn((
+                            Text::new(format!("Difficulte: {}", arena.difficulty)),
+                            TextColor(text_color),
+                        ));
+                        if !unlocked {
+                            button.spawn((Text::new("Verrouillee"), TextColor(GREY)));
+                        }
                     });
             }
         });
@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use bevy::ui::{Val, JustifyContent, AlignItems, FlexDirection, UiRect, FlexWrap};
+use crate::services::ui::constants::{GREEN, GREY, NORMAL_BUTTON, WHITE};
+use crate::services::ui::game::{GameScreen, GameButtonAction, GameScreenState};
+
+/// Affiche la vitrine des badges.
+/// Chaque badge de la campagne est présenté avec l'arène qui le donne ;
+/// les badges pas encore gagnés sont grisés et leurs caractéristiques et effets restent cachés.
+///
+/// # Arguments
+/// - `commands`: Les commandes pour créer des entités dans Bevy.
+/// - `game_state`: L'état du jeu contenant les arènes, les maîtres et le joueur.
+pub fn spawn_badge_case_screen(commands: &mut Commands, game_state: &GameScreenState) {
+    let collection = game_state.badge_collection();
+    let owned = collection
+        .iter()
+        .filter(|(_, badge)| game_state.has_badge(&badge.name))
+        .count();
+
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            display: Display::Flex,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            flex_direction: FlexDirection::Column,
+            ..Default::default()
+        },
+        BackgroundColor(Color::srgb(0.15, 0.1, 0.2)),
+        GameScreen,
+    ))
+    .with_children(|parent| {
+        parent.spawn(Text::new("Vitrine des badges"));
+        parent.spawn(Text::new(format!("{} / {} badges gagnes", owned, collection.len())));
+
+        // Une carte par badge de la campagne
+        parent
+            .spawn(Node {
+                width: Val::Percent(80.0),
+                margin: UiRect::vertical(Val::Px(20.0)),
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                justify_content: JustifyContent::SpaceEvenly,
+                ..Default::default()
+            })
+            .with_children(|case| {
+                for (arena, badge) in &collection {
+                    let won = game_state.has_badge(&badge.name);
+                    case.spawn((
+                        Node {
+                            width: Val::Px(220.0),
+                            margin: UiRect::all(Val::Px(10.0)),
+                            padding: UiRect::all(Val::Px(10.0)),
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        BackgroundColor(NORMAL_BUTTON),
+                        BorderRadius::all(Val::Px(10.0)),
+                    ))
+                    .with_children(|card| {
+                        let text_color = if won { WHITE } else { GREY };
+                        card.spawn((Text::new(badge.name.clone()), TextColor(text_color)));
+                        card.spawn((Text::new(format!("Arene {}", arena.name)), TextColor(GREY)));
+                        if won {
+                            for feature in &badge.features {
+                                card.spawn(Text::new(format!("- {}", feature)));
+                            }
+                            for effect in &badge.effects {
+                                card.spawn((Text::new(effect.label()), TextColor(GREEN)));
+                            }
+                        } else {
+                            card.spawn((Text::new("A gagner"), TextColor(GREY)));
+                        }
+                    });
+                }
+            });
+
+        // Bouton retour à l'écran principal
+        parent
+            .spawn((
+                Button,
+                Node {
+                    width: Val::Px(200.0),
+                    height: Val::Px(50.0),
+                    margin: UiRect::all(Val::Px(10.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                },
+                GameButtonAction::BackToMainGame,
+                BorderColor(Color::BLACK),
+                BorderRadius::MAX,
+                BackgroundColor(NORMAL_BUTTON),
+            ))
+            .with_child(Text::new("Retour"));
+    });
+}
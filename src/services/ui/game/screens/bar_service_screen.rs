@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use bevy::ui::{Val, JustifyContent, AlignItems, FlexDirection, UiRect};
+use crate::services::ui::constants::NORMAL_BUTTON;
+use crate::services::ui::game::{GameScreen, GameButtonAction, GameScreenState};
+use crate::services::ui::game::screens::spawn_ingredient_selection;
+
+/// Affiche le service au bar entre deux arènes.
+/// Un client passe commande : le joueur choisit les ingrédients dans son inventaire,
+/// remet les instructions dans l'ordre puis sert la boisson pour gagner un pourboire.
+/// # Arguments
+/// - `commands`: Les commandes pour créer des entités dans Bevy.
+/// - `game_state`: L'état du jeu contenant le client servi et la préparation en cours.
+pub fn spawn_bar_service_screen(commands: &mut Commands, game_state: &GameScreenState) {
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            display: Display::Flex,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            flex_direction: FlexDirection::Column,
+            ..Default::default()
+        },
+        BackgroundColor(Color::srgb(0.15, 0.1, 0.05)),
+        GameScreen,
+    ))
+    .with_children(|parent| {
+        parent.spawn(Text::new("Service au bar"));
+
+        if let Some(player) = &game_state.player {
+            parent.spawn(Text::new(format!(
+                "Votre bourse: {} | Reputation: {}",
+                player.caracter.bankroll, player.reputation
+            )));
+        }
+
+        // Résultat de la dernière commande servie
+        if let Some(message) = &game_state.bar_message {
+            parent.spawn(Text::new(message.clone()));
+        }
+
+        let Some(client) = &game_state.current_client else {
+            parent.spawn(Text::new("Aucun client ce soir."));
+            spawn_bar_button(parent, "Retour", GameButtonAction::BackToMainGame);
+            return;
+        };
+
+        // Commande du client
+        if let Some(dialog) = client.pnj.dialogs.first() {
+            parent.spawn(Text::new(format!("{}: \"{}\"", client.pnj.caracter.name, dialog)));
+        }
+        let items: Vec<String> = client.order.ingredients.iter().map(ToString::to_string).collect();
+        parent.spawn(Text::new(format!("Commande : {} ({})", client.drink, items.join(", "))));
+
+        // Ingrédients de l'inventaire
+        spawn_ingredient_selection(parent, game_state);
+
+        // Instructions de la recette pas encore choisies, à remettre dans l'ordre
+        for (index, instruction) in game_state.remaining_instructions() {
+            spawn_bar_button(parent, instruction, GameButtonAction::SelectInstruction(index));
+        }
+        let selected = game_state.selected_instructions();
+        for (i, instruction) in selected.iter().enumerate() {
+            parent.spawn(Text::new(format!("{}. {}", i + 1, instruction)));
+        }
+        if !selected.is_empty() {
+            spawn_bar_button(parent, "Annuler la derniere etape", GameButtonAction::UndoInstruction);
+            spawn_bar_button(parent, "Vider", GameButtonAction::ClearInstructions);
+        }
+
+        spawn_bar_button(parent, "Servir", GameButtonAction::ServeOrder);
+        spawn_bar_button(parent, "Retour", GameButtonAction::BackToMainGame);
+    });
+}
+
+// Bouton du bar avec son action
+fn spawn_bar_button(parent: &mut ChildBuilder, label: &str, action: GameButtonAction) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(350.0),
+                height: Val::Px(40.0),
+                margin: UiRect::all(Val::Px(5.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BorderColor(Color::BLACK),
+            BorderRadius::MAX,
+            BackgroundColor(NORMAL_BUTTON),
+            action,
+        ))
+        .with_child(Text::new(label));
+}
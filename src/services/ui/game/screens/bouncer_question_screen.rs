@@ -1,10 +1,12 @@
 use bevy::prelude::*;
 use bevy::ui::{Val, JustifyContent, AlignItems, FlexDirection, UiRect};
+use crate::models::caracter::bouncer::AnswerMode;
 use crate::services::ui::constants::NORMAL_BUTTON;
 use crate::services::ui::game::{GameScreen, GameButtonAction, GameScreenState, BouncerQuestionUI};
 
 /// Affiche l'écran de question du Bouncer
-/// Cet écran permet de poser une question au Bouncer et de proposer 4 réponses possibles.
+/// Cet écran permet de poser une question au Bouncer et de proposer 4 réponses possibles,
+/// ou un champ de saisie libre si le videur demande une réponse tapée au clavier.
 /// Il contient également un bouton pour revenir à la sélection d'arène.
 ///
 /// # Arguments
@@ -26,18 +28,82 @@ pub fn spawn_bouncer_question_screen(commands: &mut Commands, game_state: &GameS
         BouncerQuestionUI,
     ))
     .with_children(|parent| {
-        // Titre avec l'arène sélectionnée
+        // Titre avec l'arène sélectionnée et son videur
+        let bouncer_name = game_state.bouncer_name.as_deref().unwrap_or("???");
         if let Some(selected_arena) = &game_state.selected_arena {
-            parent.spawn(Text::new(format!("Arene: {} - Bouncer: Ragnar", selected_arena)));
+            parent.spawn(Text::new(format!("Arene: {} - Bouncer: {}", selected_arena, bouncer_name)));
         } else {
-            parent.spawn(Text::new("Bouncer: Ragnar"));
+            parent.spawn(Text::new(format!("Bouncer: {}", bouncer_name)));
         }
         
         // Question
         parent.spawn(Text::new(format!("Question: {}", game_state.current_question)));
+
+        // Indice, affiché seulement à la demande du joueur
+        if let Some(hint) = &game_state.current_hint {
+            if game_state.hint_revealed {
+                parent.spawn(Text::new(format!("Indice: {}", hint)));
+            } else {
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(200.0),
+                            height: Val::Px(40.0),
+                            margin: UiRect::all(Val::Px(5.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        BorderColor(Color::BLACK),
+                        BorderRadius::MAX,
+                        BackgroundColor(NORMAL_BUTTON),
+                        GameButtonAction::ShowHint,
+                    ))
+                    .with_child(Text::new("Demander un indice"));
+            }
+        }
         
-        // 4 boutons de réponse
-        for (index, option) in game_state.answer_options.iter().enumerate() {
+        // Saisie libre : la réponse tapée au clavier et un bouton pour la valider
+        if game_state.answer_mode == AnswerMode::FreeText {
+            parent.spawn((
+                Node {
+                    width: Val::Px(300.0),
+                    padding: UiRect::all(Val::Px(10.0)),
+                    margin: UiRect::all(Val::Px(5.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..Default::default()
+                },
+                BorderColor(Color::WHITE),
+                BackgroundColor(Color::srgb(0.1, 0.1, 0.1)),
+            ))
+            .with_child(Text::new(format!("{}_", game_state.typed_answer)));
+
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(50.0),
+                        margin: UiRect::all(Val::Px(5.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    BackgroundColor(NORMAL_BUTTON),
+                    GameButtonAction::SubmitTypedAnswer,
+                ))
+                .with_child(Text::new("Repondre"));
+        }
+
+        // Boutons de réponse
+        let choices = match game_state.answer_mode {
+            AnswerMode::Choices => game_state.answer_options.as_slice(),
+            AnswerMode::FreeText => &[],
+        };
+        for (index, option) in choices.iter().enumerate() {
             parent
                 .spawn((
                     Button,
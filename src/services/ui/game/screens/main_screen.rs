@@ -1,15 +1,19 @@
 use bevy::prelude::*;
 use bevy::ui::{Val, JustifyContent, AlignItems, FlexDirection, UiRect};
 use crate::services::ui::constants::{ButtonAction, NORMAL_BUTTON};
-use crate::services::ui::game::{GameScreen, GameButtonAction};
+use crate::services::ui::game::{GameScreen, GameButtonAction, GameScreenState};
 
 /// Affiche l'écran principal du jeu.
-/// Cet écran permet de sélectionner une arène pour commencer un combat.
+/// Cet écran permet de sélectionner une arène pour commencer un combat,
+/// de servir des clients au bar, d'aller à la boutique acheter des ingrédients
+/// ou de consulter la vitrine des badges.
 /// Il contient également un bouton pour revenir à l'écran précédent.
+/// La graine du hasard de la partie est affichée pour pouvoir la rejouer.
 ///
 /// # Arguments
 /// - `commands`: Les commandes pour créer des entités dans Bevy.
-pub fn spawn_main_game_screen(commands: &mut Commands) {
+/// - `game_state`: L'état du jeu contenant la graine de la partie.
+pub fn spawn_main_game_screen(commands: &mut Commands, game_state: &GameScreenState) {
     commands.spawn((
         Node {
             width: Val::Percent(100.0),
@@ -24,8 +28,8 @@ pub fn spawn_main_game_screen(commands: &mut Commands) {
         GameScreen,
     ))
     .with_children(|parent| {
-        parent.spawn(Text::new(""));
-        
+        parent.spawn(Text::new(format!("Graine : {}", game_state.rng.seed())));
+
         // Bouton pour sélectionner une arène
         parent
             .spawn((
@@ -45,6 +49,63 @@ pub fn spawn_main_game_screen(commands: &mut Commands) {
             ))
             .with_child(Text::new("Selectionner une Arene"));
 
+        // Bouton pour servir les clients du bar
+        parent
+            .spawn((
+                Button,
+                Node {
+                    width: Val::Px(250.0),
+                    height: Val::Px(50.0),
+                    margin: UiRect::all(Val::Px(10.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                },
+                BorderColor(Color::BLACK),
+                BorderRadius::MAX,
+                BackgroundColor(NORMAL_BUTTON),
+                GameButtonAction::OpenBar,
+            ))
+            .with_child(Text::new("Servir au bar"));
+
+        // Bouton pour aller à la boutique des marchands
+        parent
+            .spawn((
+                Button,
+                Node {
+                    width: Val::Px(250.0),
+                    height: Val::Px(50.0),
+                    margin: UiRect::all(Val::Px(10.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                },
+                BorderColor(Color::BLACK),
+                BorderRadius::MAX,
+                BackgroundColor(NORMAL_BUTTON),
+                GameButtonAction::OpenShop,
+            ))
+            .with_child(Text::new("Boutique"));
+
+        // Bouton pour consulter la vitrine des badges
+        parent
+            .spawn((
+                Button,
+                Node {
+                    width: Val::Px(250.0),
+                    height: Val::Px(50.0),
+                    margin: UiRect::all(Val::Px(10.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                },
+                BorderColor(Color::BLACK),
+                BorderRadius::MAX,
+                BackgroundColor(NORMAL_BUTTON),
+                GameButtonAction::OpenBadgeCase,
+            ))
+            .with_child(Text::new("Vitrine des badges"));
+
         // Bouton retour
         parent
             .spawn((
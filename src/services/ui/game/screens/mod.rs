@@ -5,6 +5,9 @@ pub mod arena_presentation_screen;
 pub mod arena_combat_screen;
 pub mod arena_crafting_screen;
 pub mod arena_end_screen;
+pub mod shop_screen;
+pub mod bar_service_screen;
+pub mod badge_case_screen;
 
 pub use main_screen::*;
 pub use arena_selection_screen::*;
@@ -12,4 +15,7 @@ pub use bouncer_question_screen::*;
 pub use arena_presentation_screen::*;
 pub use arena_combat_screen::*;
 pub use arena_crafting_screen::*;
-pub use arena_end_screen::*;
\ No newline at end of file
+pub use arena_end_screen::*;
+pub use shop_screen::*;
+pub use bar_service_screen::*;
+pub use badge_case_screen::*;
\ No newline at end of file
@@ -0,0 +1,140 @@
+use bevy::prelude::*;
+use bevy::ui::{Val, JustifyContent, AlignItems, FlexDirection, UiRect, FlexWrap};
+use crate::services::ui::constants::{GREY, NORMAL_BUTTON, SELECTED_BUTTON, WHITE};
+use crate::services::ui::game::{GameScreen, GameButtonAction, GameScreenState};
+
+/// Affiche la boutique des marchands.
+/// Le joueur choisit un marchand, parcourt son stock et achète des ingrédients avec son bankroll.
+/// Les prix tiennent compte des réductions des badges et les ingrédients trop chers sont grisés.
+///
+/// # Arguments
+/// - `commands`: Les commandes pour créer des entités dans Bevy.
+/// - `game_state`: L'état du jeu contenant les marchands et le joueur.
+pub fn spawn_shop_screen(commands: &mut Commands, game_state: &GameScreenState) {
+    let bankroll = game_state
+        .player
+        .as_ref()
+        .map_or(0, |player| player.caracter.bankroll);
+
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            display: Display::Flex,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            flex_direction: FlexDirection::Column,
+            ..Default::default()
+        },
+        BackgroundColor(Color::srgb(0.2, 0.15, 0.1)),
+        GameScreen,
+    ))
+    .with_children(|parent| {
+        parent.spawn(Text::new("Boutique"));
+
+        // Bourse et inventaire du joueur
+        if let Some(player) = &game_state.player {
+            parent.spawn(Text::new(format!(
+                "Votre bourse: {} | Inventaire: {} ingredient(s)",
+                player.caracter.bankroll,
+                player.inventory.iter().map(|stack| stack.quantity).sum::<u32>()
+            )));
+        }
+
+        // Résultat du dernier achat
+        if let Some(message) = &game_state.shop_message {
+            parent.spawn(Text::new(message.clone()));
+        }
+
+        // Un bouton par marchand
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                justify_content: JustifyContent::Center,
+                ..Default::default()
+            })
+            .with_children(|row| {
+                for (index, trader) in game_state.traders.iter().enumerate() {
+                    let selected = game_state.selected_trader == Some(index);
+                    row.spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(200.0),
+                            height: Val::Px(60.0),
+                            margin: UiRect::all(Val::Px(5.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            flex_direction: FlexDirection::Column,
+                            ..Default::default()
+                        },
+                        BorderColor(Color::BLACK),
+                        BorderRadius::MAX,
+                        BackgroundColor(if selected { SELECTED_BUTTON } else { NORMAL_BUTTON }),
+                        GameButtonAction::ChooseTrader(index),
+                    ))
+                    .with_children(|button| {
+                        button.spawn(Text::new(trader.pnj.caracter.name.clone()));
+                        button.spawn((Text::new(trader.pnj.job.clone()), TextColor(GREY)));
+                    });
+                }
+            });
+
+        // Stock du marchand choisi
+        if let Some(trader) = game_state
+            .selected_trader
+            .and_then(|index| game_state.traders.get(index))
+        {
+            if let Some(dialog) = trader.pnj.dialogs.first() {
+                parent.spawn(Text::new(format!("{}: \"{}\"", trader.pnj.caracter.name, dialog)));
+            }
+
+            for (index, ingredient) in trader.ingredients.iter().enumerate() {
+                let price = game_state.trader_price(ingredient);
+                let affordable = price <= bankroll;
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(400.0),
+                            height: Val::Px(40.0),
+                            margin: UiRect::all(Val::Px(3.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        BorderColor(Color::BLACK),
+                        BorderRadius::MAX,
+                        BackgroundColor(NORMAL_BUTTON),
+                        GameButtonAction::BuyIngredient(index),
+                    ))
+                    .with_child((
+                        Text::new(format!(
+                            "{} ({}) - {} pieces",
+                            ingredient.name, ingredient.description, price
+                        )),
+                        TextColor(if affordable { WHITE } else { GREY }),
+                    ));
+            }
+        }
+
+        // Bouton retour à l'écran principal
+        parent
+            .spawn((
+                Button,
+                Node {
+                    width: Val::Px(200.0),
+                    height: Val::Px(50.0),
+                    margin: UiRect::all(Val::Px(10.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                },
+                GameButtonAction::BackToMainGame,
+                BorderColor(Color::BLACK),
+                BorderRadius::MAX,
+                BackgroundColor(NORMAL_BUTTON),
+            ))
+            .with_child(Text::new("Retour"));
+    });
+}
@@ -1,76 +1,26 @@
 use bevy::prelude::*;
-use crate::models::recipe::Recipe;
+use crate::services::engine::GameEngine;
 
-/// État du jeu, qui contient les informations sur l'écran actuel, les questions, les arènes, etc.
-#[derive(Resource, Default)]
-pub struct GameScreenState {
-    pub current_screen: GameScreenType,
-    pub current_question: String,
-    pub answer_options: Vec<String>,
-    pub correct_answer: String,
-    pub available_arenas: Vec<(String, String)>,
-    pub selected_arena: Option<String>,
-    pub wrong_answer_message: bool,
-    pub master_name: Option<String>,
-    pub master_style: Option<String>,
-    pub master_attacks: Vec<String>,
-    pub master_dialogs: Vec<String>,
-    pub master_badge: Option<String>,
-    pub selected_arena_index: Option<usize>,
-    pub arena_combat_state: ArenaCombatState,
-    pub player_hp: i32,
-    pub boss_hp: i32,
-    pub show_intro_screen: bool,
-    pub master_recipe: Option<Recipe>,
-    pub current_boss_attack: Option<String>,
-    pub current_crafting: CurrentCocktailCrafting,
-    pub show_crafting_phase: bool,
-}
+// Les types d'état sont définis dans le moteur de jeu, on les réexporte pour l'interface
+pub use crate::services::engine::state::{ArenaCombatState, GameScreenType};
+// La session (dossier des sauvegardes, joueur en cours) est partagée avec l'interface terminal
+pub use crate::services::session::{CurrentPlayer, SaveDirectory};
 
-/// Implémentation des méthodes pour l'état du jeu
-impl GameScreenState {
-    /// Réinitialise l'état du combat
-    pub fn reset_combat(&mut self) {
-        self.arena_combat_state = ArenaCombatState::Start;
-        self.player_hp = 100;
-        self.boss_hp = 100;
-        self.show_intro_screen = true;
-        self.show_crafting_phase = false;
-        self.current_boss_attack = None;
-        self.current_crafting = CurrentCocktailCrafting::default();
-    }
-}
+impl Resource for SaveDirectory {}
+impl Resource for CurrentPlayer {}
 
-/// Types d'écrans du jeu
-#[derive(Default, PartialEq)]
-pub enum GameScreenType {
-    #[default]
-    Main,
-    ArenaSelection,
-    ArenaPresentation,
-    BouncerQuestion,
-    Arena,
-}
+/// État du jeu, qui contient les informations sur l'écran actuel, les questions, les arènes, etc.
+/// Il s'agit d'une ressource Bevy qui enveloppe le moteur de jeu : les écrans lisent
+/// directement ses champs et le gestionnaire de boutons lui envoie des commandes.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct GameScreenState(pub GameEngine);
 
-/// État du combat dans l'Arene
-#[derive(Default, PartialEq)]
-pub enum ArenaCombatState {
-    #[default]
-    Start,
-    PlayerTurn,
-    BossTurn,
-    Victory,
-    Defeat,
-}
+/// Arène dans laquelle entrer directement au lancement du jeu, pour les tests.
+/// Elle n'est utilisée qu'à la première entrée en jeu.
+#[derive(Resource, Default)]
+pub struct StartArena(pub Option<String>);
 
-/// État de l'artisanat de cocktail en cours
-#[derive(Default)]
-pub struct CurrentCocktailCrafting {
-    pub selected_ingredients: Vec<String>,
-    pub completed: bool,
-    pub correct: bool,
-    pub selected_instructions: Vec<String>,
-    pub instruction_correct: bool,
-    pub selected_recipe: Option<Recipe>,
-    pub cocktail_ready: bool,
-}
\ No newline at end of file
+/// Graine du hasard choisie en ligne de commande, ou celle de la session enregistrée ou rejouée.
+/// Sans graine, chaque entrée en jeu tire une nouvelle graine aléatoire.
+#[derive(Resource, Default)]
+pub struct GameSeed(pub Option<u64>);
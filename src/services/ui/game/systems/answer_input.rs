@@ -0,0 +1,61 @@
+use crate::models::caracter::bouncer::AnswerMode;
+use crate::services::engine::GameCommand;
+use crate::services::ui::game::{GameScreen, GameScreenState, GameScreenType};
+use crate::services::ui::game::systems::button_handler::spawn_current_screen;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+/// Longueur maximale d'une réponse tapée au videur
+const MAX_TYPED_ANSWER_LEN: usize = 40;
+
+/// Gère la saisie au clavier de la réponse au videur.
+///
+/// Les lettres s'ajoutent à la réponse, Retour arrière efface le dernier caractère
+/// et Entrée valide la réponse. Le système ne fait rien si le videur propose des choix.
+pub fn handle_typed_answer_input(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut commands: Commands,
+    mut game_state: ResMut<GameScreenState>,
+    game_entities: Query<Entity, With<GameScreen>>,
+) {
+    if game_state.current_screen != GameScreenType::BouncerQuestion
+        || game_state.answer_mode != AnswerMode::FreeText
+    {
+        keyboard_events.clear();
+        return;
+    }
+
+    let mut typed_answer = game_state.typed_answer.clone();
+    let mut submit = false;
+    for event in keyboard_events.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(text) if typed_answer.chars().count() < MAX_TYPED_ANSWER_LEN => {
+                typed_answer.push_str(text);
+            }
+            Key::Space if typed_answer.chars().count() < MAX_TYPED_ANSWER_LEN => {
+                typed_answer.push(' ');
+            }
+            Key::Backspace => {
+                typed_answer.pop();
+            }
+            Key::Enter => submit = true,
+            _ => {}
+        }
+    }
+
+    let mut events = game_state.apply(GameCommand::TypeAnswer(typed_answer));
+    if submit {
+        events.extend(game_state.apply(GameCommand::SubmitTypedAnswer));
+    }
+
+    // Le moteur n'a rien changé, inutile de redessiner
+    if !events.is_empty() {
+        for entity in game_entities.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        spawn_current_screen(&mut commands, &game_state);
+    }
+}
@@ -1,3 +1,5 @@
+pub mod answer_input;
 pub mod button_handler;
 
-pub use button_handler::handle_game_button_actions;
\ No newline at end of file
+pub use answer_input::handle_typed_answer_input;
+pub use button_handler::handle_game_button_actions;
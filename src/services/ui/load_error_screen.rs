@@ -0,0 +1,105 @@
+use crate::services::json_loader::LoadError;
+use crate::services::ui::constants::{AppState, ButtonAction, NORMAL_BUTTON, RED};
+use bevy::prelude::*;
+use bevy::ui::{AlignItems, FlexDirection, JustifyContent, UiRect, Val};
+
+/// Marqueur de composant pour identifier les entités de l'écran d'erreur de chargement
+#[derive(Component)]
+pub struct LoadErrorScreen;
+
+/// Ressource qui contient les erreurs rencontrées en chargeant les fichiers de données.
+/// Si elle n'est pas vide, le jeu affiche l'écran d'erreur au lieu de démarrer sans contenu.
+#[derive(Resource, Default)]
+pub struct LoadErrors {
+    pub errors: Vec<LoadError>,
+}
+
+/// Plugin pour l'écran d'erreur de chargement
+pub struct LoadErrorScreenPlugin;
+
+impl Plugin for LoadErrorScreenPlugin {
+    // On configure les systèmes de l'écran d'erreur
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LoadErrors>()
+            .add_systems(Startup, show_startup_load_errors)
+            .add_systems(OnEnter(AppState::LoadError), setup_load_error_screen)
+            .add_systems(OnExit(AppState::LoadError), despawn_load_error_screen);
+    }
+}
+
+/// Affiche l'écran d'erreur dès le démarrage si un fichier n'a pas pu être chargé
+pub fn show_startup_load_errors(
+    load_errors: Res<LoadErrors>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if !load_errors.errors.is_empty() {
+        next_state.set(AppState::LoadError);
+    }
+}
+
+/// On crée l'écran qui liste les fichiers en erreur avec leur position dans le fichier
+/// # Arguments
+/// - `commands`: Les commandes pour créer des entités dans Bevy.
+/// - `load_errors`: Les erreurs de chargement à afficher.
+pub fn setup_load_error_screen(mut commands: Commands, load_errors: Res<LoadErrors>) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                display: Display::Flex,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgb(0.2, 0.1, 0.1)),
+            LoadErrorScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn(Text::new("Impossible de charger les donnees du jeu"));
+
+            // Une ligne par fichier en erreur
+            for error in &load_errors.errors {
+                parent.spawn((
+                    Text::new(error.to_string()),
+                    TextColor(RED),
+                    Node {
+                        margin: UiRect::all(Val::Px(5.0)),
+                        ..Default::default()
+                    },
+                ));
+            }
+
+            // Boutons de retour au menu principal et pour quitter
+            for (label, action) in [("Retour", ButtonAction::Back), ("Quitter", ButtonAction::Quit)] {
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(200.0),
+                            height: Val::Px(50.0),
+                            margin: UiRect::all(Val::Px(10.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        action,
+                        BorderColor(Color::BLACK),
+                        BorderRadius::MAX,
+                        BackgroundColor(NORMAL_BUTTON),
+                    ))
+                    .with_child(Text::new(label));
+            }
+        });
+}
+
+/// On nettoie l'écran d'erreur en supprimant toutes les entités associées
+pub fn despawn_load_error_screen(
+    mut commands: Commands,
+    query: Query<Entity, With<LoadErrorScreen>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
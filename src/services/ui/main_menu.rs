@@ -34,9 +34,7 @@ pub fn setup_main_menu(mut commands: Commands) {
             ] {
                 parent
                     .spawn((
-                        Button {
-                            ..Default::default()
-                        },
+                        Button,
                         action,
                     ))
                     .with_children(|button| {
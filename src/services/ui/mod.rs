@@ -2,6 +2,14 @@ pub mod constants;
 pub mod main_menu;
 pub mod aptitudes_screen;
 pub mod game;  
+pub mod load_error_screen;
 pub mod player_slot_screen;
 pub mod player_creation_screen;
-pub mod start_screen;
\ No newline at end of file
+pub mod replay;
+pub mod start_screen;
+
+use crate::services::content::GameContent;
+use bevy::prelude::Resource;
+
+// Le contenu du jeu, chargé une seule fois au démarrage, est partagé par tous les écrans
+impl Resource for GameContent {}
@@ -1,52 +1,49 @@
 use bevy::prelude::*;
-use crate::services::ui::constants::{AppState, ButtonAction, NORMAL_BUTTON, BLACK, GREEN};
+use crate::services::ui::constants::{AppState, ButtonAction, ChangedButton, NORMAL_BUTTON, BLACK, GREEN, GREY};
 use crate::services::ui::player_slot_screen::SelectedPlayerSlot;
-use crate::models::aptitude::Aptitude;
-use crate::models::caracter::player::Player;
-use crate::models::badge::Badge;
-use crate::models::ingredient::Ingredient;
+use crate::models::save_game::SaveGame;
 use bevy::ecs::system::ParamSet;
+use crate::services::content::GameContent;
 use crate::services::json_loader::JsonLoader;
+use crate::services::session::{aptitude_picks, create_player, toggle_aptitude};
+use crate::services::engine::rng::GameRng;
+use crate::services::ui::game::{GameSeed, SaveDirectory};
+use serde_derive::{Deserialize, Serialize};
 
 /// Composant pour marquer les entites de l'ecran de creation de personnage
 #[derive(Component)]
 pub struct PlayerCreationScreen;
 
-/// Ressource pour stocker les donnees temporaires de creation de personnage
-#[derive(Resource)]
-pub struct PlayerCreationData {
-    pub name: String,
-    pub style: String,
-    pub selected_badge_index: Option<usize>,
-    pub selected_aptitudes: Vec<usize>,
-}
+// Les choix de création sont partagés avec l'interface terminal, on en fait une ressource
+pub use crate::services::session::PlayerCreationData;
 
-impl Default for PlayerCreationData {
-    fn default() -> Self {
-        Self {
-            name: String::new(),
-            style: String::new(),
-            selected_badge_index: None,
-            selected_aptitudes: Vec::new(),
-        }
-    }
-}
+impl Resource for PlayerCreationData {}
 
 /// Composant pour les champs de texte
-#[derive(Component)]
+#[derive(Component, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum TextInput {
     Name,
     Style,
 }
 
-/// Composant pour les choix de badges
+/// Composant pour les choix de badges de départ, index dans `badges.json`
 #[derive(Component)]
 pub struct BadgeChoice(pub usize);
 
-/// Composant pour les choix d'aptitudes
+/// Composant pour les choix d'aptitudes, index dans `aptitudes.json`
 #[derive(Component)]
 pub struct AptitudeChoice(pub usize);
 
+// Choix cliqués, puis tous les choix du même type pour mettre à jour leurs couleurs
+type ChoiceButtons<'w, 's, C> = ParamSet<
+    'w,
+    's,
+    (
+        Query<'static, 'static, (&'static Interaction, &'static C), ChangedButton>,
+        Query<'static, 'static, (&'static C, &'static mut BackgroundColor), With<Button>>,
+    ),
+>;
+
 /// Systemes de creation de personnage
 pub struct PlayerCreationPlugin;
 
@@ -62,10 +59,14 @@ impl Plugin for PlayerCreationPlugin {
     }
 }
 
+/// Affiche l'écran de création de personnage.
+/// Les badges de départ et les aptitudes proposés viennent des fichiers de données,
+/// avec leur description, et le nombre d'aptitudes à choisir est celui du premier niveau.
 pub fn setup_player_creation_screen(
     mut commands: Commands,
     selected_slot: Res<SelectedPlayerSlot>,
     mut creation_data: ResMut<PlayerCreationData>,
+    content: Res<GameContent>,
 ) {
     // Reinitialise les donnees de creation
     *creation_data = PlayerCreationData::default();
@@ -150,15 +151,16 @@ pub fn setup_player_creation_screen(
                             ..Default::default()
                         })
                         .with_children(|badges_container| {
-                            // Exemple de badges
-                            for i in 0..3 {
+                            for (i, badge) in content.badges.iter().enumerate() {
                                 badges_container.spawn((
                                     Button,
                                     Node {
-                                        width: Val::Px(100.0),
-                                        height: Val::Px(100.0),
+                                        width: Val::Px(220.0),
+                                        min_height: Val::Px(100.0),
                                         margin: UiRect::horizontal(Val::Px(10.0)),
+                                        padding: UiRect::all(Val::Px(5.0)),
                                         display: Display::Flex,
+                                        flex_direction: FlexDirection::Column,
                                         justify_content: JustifyContent::Center,
                                         align_items: AlignItems::Center,
                                         ..Default::default()
@@ -167,8 +169,12 @@ pub fn setup_player_creation_screen(
                                     BackgroundColor(NORMAL_BUTTON),
                                     BadgeChoice(i),
                                 ))
-                                .with_children(|badge| {
-                                    badge.spawn(Text::new(format!("Badge {}", i + 1)));
+                                .with_children(|button| {
+                                    button.spawn(Text::new(badge.name.clone()));
+                                    button.spawn((Text::new(badge.features.join(", ")), TextColor(GREY)));
+                                    for effect in &badge.effects {
+                                        button.spawn((Text::new(effect.label()), TextColor(GREEN)));
+                                    }
                                 });
                             }
                         });
@@ -182,7 +188,10 @@ pub fn setup_player_creation_screen(
                         ..Default::default()
                     })
                     .with_children(|section| {
-                        section.spawn(Text::new("Choisissez des aptitudes:"));
+                        section.spawn(Text::new(format!(
+                            "Choisissez {} aptitude(s):",
+                            aptitude_picks(&content)
+                        )));
                         
                         section.spawn(Node {
                             display: Display::Flex,
@@ -193,15 +202,16 @@ pub fn setup_player_creation_screen(
                             ..Default::default()
                         })
                         .with_children(|aptitudes_container| {
-                            // Exemple d'aptitudes
-                            for i in 0..6 {
+                            for (i, aptitude) in content.aptitudes.iter().enumerate() {
                                 aptitudes_container.spawn((
                                     Button,
                                     Node {
-                                        width: Val::Px(120.0),
-                                        height: Val::Px(50.0),
+                                        width: Val::Px(220.0),
+                                        min_height: Val::Px(80.0),
                                         margin: UiRect::all(Val::Px(5.0)),
+                                        padding: UiRect::all(Val::Px(5.0)),
                                         display: Display::Flex,
+                                        flex_direction: FlexDirection::Column,
                                         justify_content: JustifyContent::Center,
                                         align_items: AlignItems::Center,
                                         ..Default::default()
@@ -210,8 +220,9 @@ pub fn setup_player_creation_screen(
                                     BackgroundColor(NORMAL_BUTTON),
                                     AptitudeChoice(i),
                                 ))
-                                .with_children(|aptitude| {
-                                    aptitude.spawn(Text::new(format!("Aptitude {}", i + 1)));
+                                .with_children(|button| {
+                                    button.spawn(Text::new(format!("{} ({} PP)", aptitude.name, aptitude.pp)));
+                                    button.spawn((Text::new(aptitude.description.clone()), TextColor(GREY)));
                                 });
                             }
                         });
@@ -280,11 +291,15 @@ pub fn despawn_player_creation_screen(
 }
 
 // Systeme de gestion des saisies texte ( avec noms aleatoires)
+// Les tirages suivent la graine de la partie : une session rejouée retrouve les mêmes noms
 pub fn handle_text_input(
-    mut interaction_query: Query<(&Interaction, &TextInput, &Children), (Changed<Interaction>, With<Button>)>,
+    mut interaction_query: Query<(&Interaction, &TextInput, &Children), ChangedButton>,
     mut text_query: Query<&mut Text>,
     mut creation_data: ResMut<PlayerCreationData>,
+    seed: Res<GameSeed>,
+    mut rng: Local<Option<GameRng>>,
 ) {
+    let rng = rng.get_or_insert_with(|| seed.0.map(GameRng::new).unwrap_or_default());
     for (interaction, input_type, children) in interaction_query.iter_mut() {
         if let Interaction::Pressed = *interaction {
             // Utilise un nom aleatoire au lieu d'une saisie reelle
@@ -299,12 +314,7 @@ pub fn handle_text_input(
                     ];
                     
                     // Choisir un nom aleatoire
-                    let random_index = (std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_millis() % hero_names.len() as u128) as usize;
-                    
-                    creation_data.name = hero_names[random_index].to_string();
+                    creation_data.name = rng.choose(&hero_names).copied().unwrap_or_default().to_string();
                     
                     if let Ok(mut text) = text_query.get_mut(*children.first().unwrap()) {
                         *text = Text::new(creation_data.name.clone());
@@ -318,12 +328,7 @@ pub fn handle_text_input(
                     ];
                     
                     // Choisir un style aleatoire
-                    let random_index = (std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_millis() % combat_styles.len() as u128) as usize;
-                    
-                    creation_data.style = combat_styles[random_index].to_string();
+                    creation_data.style = rng.choose(&combat_styles).copied().unwrap_or_default().to_string();
                     
                     if let Ok(mut text) = text_query.get_mut(*children.first().unwrap()) {
                         *text = Text::new(creation_data.style.clone());
@@ -336,10 +341,7 @@ pub fn handle_text_input(
 
 // Systeme de gestion de la selection de badge
 pub fn handle_badge_selection(
-    mut params: ParamSet<(
-        Query<(&Interaction, &BadgeChoice), (Changed<Interaction>, With<Button>)>,
-        Query<(&BadgeChoice, &mut BackgroundColor), With<Button>>
-    )>,
+    mut params: ChoiceButtons<BadgeChoice>,
     mut creation_data: ResMut<PlayerCreationData>,
 ) {
     // Trouve quel badge a ete clique
@@ -383,12 +385,11 @@ pub fn handle_badge_selection(
 }
 
 // Systeme de gestion de la selection d'aptitudes
+// Au-delà du nombre d'aptitudes autorisé, la plus ancienne sélection est remplacée
 pub fn handle_aptitude_selection(
-    mut params: ParamSet<(
-        Query<(&Interaction, &AptitudeChoice), (Changed<Interaction>, With<Button>)>,
-        Query<(&AptitudeChoice, &mut BackgroundColor), With<Button>>
-    )>,
+    mut params: ChoiceButtons<AptitudeChoice>,
     mut creation_data: ResMut<PlayerCreationData>,
+    content: Res<GameContent>,
 ) {
     let mut clicked_index = None;
     
@@ -401,99 +402,56 @@ pub fn handle_aptitude_selection(
     
     // Si une aptitude a ete cliquee
     if let Some(index) = clicked_index {
-        // Si on clique sur une aptitude dejà selectionnee, on la deselectionne
-        if creation_data.selected_aptitudes.contains(&index) {
-            creation_data.selected_aptitudes.retain(|&i| i != index);
-            
-            // Mise à jour des couleurs des boutons
-            for (choice, mut bg_color) in params.p1().iter_mut() {
-                if choice.0 == index {
-                    *bg_color = BackgroundColor(NORMAL_BUTTON);
-                }
-            }
-        } else {
-            // Remplace la selection actuelle par la nouvelle aptitude
-            creation_data.selected_aptitudes.clear();
-            creation_data.selected_aptitudes.push(index);
-            
-            // Mise à jour des couleurs de tous les boutons
-            for (choice, mut bg_color) in params.p1().iter_mut() {
-                if choice.0 == index {
-                    *bg_color = BackgroundColor(GREEN);
-                } else {
-                    *bg_color = BackgroundColor(NORMAL_BUTTON);
-                }
-            }
+        toggle_aptitude(&mut creation_data.selected_aptitudes, index, aptitude_picks(&content));
+
+        // Mise à jour des couleurs de tous les boutons
+        for (choice, mut bg_color) in params.p1().iter_mut() {
+            *bg_color = if creation_data.selected_aptitudes.contains(&choice.0) {
+                BackgroundColor(GREEN)
+            } else {
+                BackgroundColor(NORMAL_BUTTON)
+            };
         }
     }
 }
 
 // Systeme de gestion de la confirmation de creation
 pub fn handle_creation_confirmation(
-    interaction_query: Query<(&Interaction, &ButtonAction), (Changed<Interaction>, With<Button>)>,
+    interaction_query: Query<(&Interaction, &ButtonAction), ChangedButton>,
     creation_data: Res<PlayerCreationData>,
     selected_slot: Res<SelectedPlayerSlot>,
+    content: Res<GameContent>,
+    save_dir: Res<SaveDirectory>,
     mut next_state: ResMut<NextState<AppState>>,
 ) {
 
-    JsonLoader::ensure_save_directory();
+    JsonLoader::ensure_save_directory(&save_dir.0);
 
     for (interaction, action) in interaction_query.iter() {
         if let Interaction::Pressed = *interaction {
             if let ButtonAction::CreatePlayer = action {
                 // Verifie que les informations necessaires sont renseignees
-                if !creation_data.name.is_empty() && 
-                   creation_data.selected_badge_index.is_some() && !creation_data.selected_aptitudes.is_empty() {
-                    
-                    // Creer le joueur
-                    let player = create_player(&creation_data);
-                    
-                    // Determine le chemin du fichier base sur le slot
-                    let slot_index = selected_slot.slot.unwrap_or(0);
-                    let file_path = format!("save/player_slot_{}.json", slot_index + 1);
-                    
-                    // Sauvegarde le joueur dans un fichier JSON
-                    match JsonLoader::save_player_to_json(&file_path, &player) {
-                        Ok(_) => {
-                            println!("Joueur sauvegarde dans {}", file_path);
-                            // Revenir à l'ecran de selection de slot ou au menu principal
-                            next_state.set(AppState::PlayerSlot);
-                        },
-                        Err(e) => {
-                            println!("Erreur lors de la sauvegarde du joueur: {}", e);
+                match create_player(&creation_data, &content) {
+                    Ok(player) => {
+                        // Determine le chemin du fichier base sur le slot
+                        let slot_index = selected_slot.slot.unwrap_or(0);
+                        let file_path = save_dir.slot_path(slot_index);
+
+                        // Sauvegarde le joueur dans une nouvelle partie au format actuel
+                        match JsonLoader::save_game(&file_path, &SaveGame::new(player)) {
+                            Ok(_) => {
+                                println!("Joueur sauvegarde dans {}", file_path);
+                                // Revenir à l'ecran de selection de slot ou au menu principal
+                                next_state.set(AppState::PlayerSlot);
+                            },
+                            Err(e) => {
+                                println!("Erreur lors de la sauvegarde du joueur: {}", e);
+                            }
                         }
                     }
-                } else {
-                    println!("Veuillez completer toutes les informations avant de creer le personnage.");
+                    Err(message) => println!("{}", message),
                 }
             }
         }
     }
 }
-
-// Fonction pour creer un nouveau joueur à partir des donnees de creation
-pub fn create_player(creation_data: &PlayerCreationData) -> Player {
-    let badge = Badge {
-        name: format!("Badge {}", creation_data.selected_badge_index.unwrap_or(0) + 1),
-        features: vec!["Feature 1".to_string(), "Feature 2".to_string()],
-    };
-    
-    let aptitudes = creation_data
-        .selected_aptitudes
-        .iter()
-        .map(|&index| Aptitude {
-            name: format!("Aptitude {}", index + 1),
-            description: "Description de l'aptitude".to_string(),
-            pp: 10,
-            power: 5.0,
-        })
-        .collect();
-    
-    Player::new(
-        &creation_data.name,
-        &creation_data.style,
-        badge,
-        Vec::<Ingredient>::new(),
-        aptitudes,
-    )
-}
\ No newline at end of file
@@ -1,35 +1,23 @@
 use crate::services::ui::constants::{
     AppState, ButtonAction, GameLoadContext, BLACK, BLUE, NORMAL_BUTTON, SELECTED_BUTTON,
 };
+use crate::services::ui::game::SaveDirectory;
 use bevy::prelude::*;
-use serde_json::Value;
-use std::fs::File;
-use std::path::Path;
 
 // Composant pour marquer les entites de l'ecran de selection de slot
 #[derive(Component)]
 pub struct PlayerSlotScreen;
 
 // Ressource pour stocker le slot de joueur selectionne
-#[derive(Resource)]
+#[derive(Resource, Default)]
 pub struct SelectedPlayerSlot {
     pub slot: Option<usize>,
 }
 
-// Structure pour stocker les informations des slots
-#[derive(Resource)]
-pub struct SlotInfo {
-    // Vecteur de 3 slots chaque élément contient le nom du joueur ou None si vide
-    pub info: Vec<Option<String>>,
-}
+// Les informations des slots sont lues sans Bevy, on en fait une ressource pour l'interface
+pub use crate::services::session::SlotInfo;
 
-impl Default for SlotInfo {
-    fn default() -> Self {
-        Self {
-            info: vec![None, None, None],
-        }
-    }
-}
+impl Resource for SlotInfo {}
 
 // Plugin pour gérer l'écran de sélection des slots de sauvegarde
 
@@ -50,67 +38,11 @@ impl Plugin for PlayerSlotScreenPlugin {
     }
 }
 
-impl Default for SelectedPlayerSlot {
-    fn default() -> Self {
-        Self { slot: None }
-    }
-}
 
 // On charge les informations des 3 slots de sauvegarde depuis les fichiers JSON
 
-pub fn load_player_slots(mut slot_info: ResMut<SlotInfo>) {
-    // On crée le dossier de sauvegarde si nécessaire
-
-    if !Path::new("save").exists() {
-        if let Err(e) = std::fs::create_dir_all("save") {
-            println!("Erreur lors de la creation du dossier de sauvegarde: {}", e);
-        }
-    }
-
-    // On réinitialise les informations de slot
-    slot_info.info = vec![None, None, None];
-
-    // On vérifie chaque slot (1, 2, 3)
-
-    for i in 0..3 {
-        let file_path = format!("save/player_slot_{}.json", i + 1);
-        if Path::new(&file_path).exists() {
-            // On tente de lire le fichier JSON pour extraire le nom du joueur
-            match File::open(&file_path) {
-                Ok(file) => {
-                    match serde_json::from_reader::<_, Value>(file) {
-                        Ok(json) => {
-                            // On parcours les entrées pour trouver le nom du personnage
-
-                            if let Some(obj) = json.as_object() {
-                                for (_, player_data) in obj {
-                                    // On accède à caracter.name
-                                    if let Some(character) = player_data.get("caracter") {
-                                        if let Some(name) =
-                                            character.get("name").and_then(|n| n.as_str())
-                                        {
-                                            slot_info.info[i] = Some(name.to_string());
-                                            break; // On prend le premier personnage trouve
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                        Err(e) => println!(
-                            "Erreur lors de la lecture du JSON pour le slot {}: {}",
-                            i + 1,
-                            e
-                        ),
-                    }
-                }
-                Err(e) => println!(
-                    "Erreur lors de l'ouverture du fichier pour le slot {}: {}",
-                    i + 1,
-                    e
-                ),
-            }
-        }
-    }
+pub fn load_player_slots(mut slot_info: ResMut<SlotInfo>, save_dir: Res<SaveDirectory>) {
+    *slot_info = SlotInfo::load(&save_dir);
 }
 
 // On crée l'interface de sélection des slots de sauvegarde
@@ -194,9 +126,32 @@ pub fn setup_player_slot_screen(
                                 // on affiche le nom du personnage s'il existe sinon "Vide"
                                 let slot_text = match &slot_info.info[i] {
                                     Some(name) => name.clone(),
+                                    None if slot_info.restorable[i] => "Illisible".to_string(),
                                     None => "Vide".to_string(),
                                 };
                                 button.spawn(Text::new(slot_text));
+
+                                // Bouton de restauration si le fichier du slot est corrompu
+                                if slot_info.restorable[i] {
+                                    button
+                                        .spawn((
+                                            Button,
+                                            Node {
+                                                width: Val::Px(120.0),
+                                                height: Val::Px(30.0),
+                                                margin: UiRect::top(Val::Px(10.0)),
+                                                justify_content: JustifyContent::Center,
+                                                align_items: AlignItems::Center,
+                                                ..Default::default()
+                                            },
+                                            BorderColor(BLACK),
+                                            BackgroundColor(NORMAL_BUTTON),
+                                            ButtonAction::RestoreSlot(i),
+                                        ))
+                                        .with_children(|restore| {
+                                            restore.spawn(Text::new("Restaurer"));
+                                        });
+                                }
                             });
                     }
                 });
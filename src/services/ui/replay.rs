@@ -0,0 +1,234 @@
+use crate::services::replay::{RecordedInput, ReplayFrontend, ReplayPlayer, ReplayRecorder};
+use crate::services::ui::constants::{ButtonAction, ChangedButton};
+use crate::services::ui::game::GameButtonAction;
+use crate::services::ui::player_creation_screen::{AptitudeChoice, BadgeChoice, TextInput};
+use bevy::input::keyboard::{Key, KeyboardInput, NativeKeyCode};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use bevy::window::PrimaryWindow;
+use serde_derive::{Deserialize, Serialize};
+
+/// Entrée brute de l'interface Bevy, telle qu'elle est enregistrée pour le rejeu :
+/// un clic sur un bouton, repéré par l'action qu'il porte, ou une touche du clavier
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BevyInput {
+    /// Bouton des menus
+    Menu(ButtonAction),
+    /// Bouton de l'écran de jeu
+    Game(GameButtonAction),
+    /// Champ de texte de l'écran de création
+    TextField(TextInput),
+    /// Badge de départ de l'écran de création, index dans `badges.json`
+    Badge(usize),
+    /// Aptitude de l'écran de création, index dans `aptitudes.json`
+    Aptitude(usize),
+    Key(RecordedKey),
+}
+
+impl RecordedInput for BevyInput {
+    const FRONTEND: ReplayFrontend = ReplayFrontend::Bevy;
+}
+
+/// Touche du clavier enregistrée : seules les touches lues par les saisies du jeu le sont
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RecordedKey {
+    Character(String),
+    Space,
+    Backspace,
+    Enter,
+}
+
+impl RecordedKey {
+    // Touche à enregistrer pour une touche pressée, `None` si aucune saisie ne la lit
+    fn from_key(key: &Key) -> Option<Self> {
+        match key {
+            Key::Character(text) => Some(RecordedKey::Character(text.to_string())),
+            Key::Space => Some(RecordedKey::Space),
+            Key::Backspace => Some(RecordedKey::Backspace),
+            Key::Enter => Some(RecordedKey::Enter),
+            _ => None,
+        }
+    }
+
+    // Touche logique envoyée au jeu quand l'entrée est rejouée
+    fn to_key(&self) -> Key {
+        match self {
+            RecordedKey::Character(text) => Key::Character(text.as_str().into()),
+            RecordedKey::Space => Key::Space,
+            RecordedKey::Backspace => Key::Backspace,
+            RecordedKey::Enter => Key::Enter,
+        }
+    }
+}
+
+/// Enregistrement des entrées du joueur, demandé en ligne de commande avec `--record`
+#[derive(Resource, Default)]
+pub struct ReplayRecording(pub Option<ReplayRecorder<BevyInput>>);
+
+/// Session rejouée à la place du joueur, demandée en ligne de commande avec `--replay`
+#[derive(Resource, Default)]
+pub struct ReplayPlayback(pub Option<ReplayPlayer<BevyInput>>);
+
+/// Bandeau affiché pendant le rejeu, par-dessus les écrans du jeu
+#[derive(Component)]
+pub struct ReplayBanner;
+
+/// Enregistrement et rejeu des sessions de jeu.
+/// Les deux systèmes tournent juste après la détection des clics par Bevy,
+/// avant tous les systèmes qui lisent les boutons et le clavier.
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReplayRecording>()
+            .init_resource::<ReplayPlayback>()
+            .add_systems(Startup, spawn_replay_banner)
+            .add_systems(
+                PreUpdate,
+                (record_inputs, play_replay).chain().after(UiSystem::Focus),
+            );
+    }
+}
+
+/// Affiche le bandeau du rejeu si une session est rejouée
+pub fn spawn_replay_banner(mut commands: Commands, playback: Res<ReplayPlayback>) {
+    if playback.0.is_none() {
+        return;
+    }
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(8.0),
+            right: Val::Px(8.0),
+            padding: UiRect::all(Val::Px(6.0)),
+            ..Default::default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+        GlobalZIndex(10),
+        Text::new("Rejeu en cours"),
+        ReplayBanner,
+    ));
+}
+
+// Composants qui identifient l'action d'un bouton
+type ButtonComponents<'a> = (
+    Option<&'a ButtonAction>,
+    Option<&'a GameButtonAction>,
+    Option<&'a TextInput>,
+    Option<&'a BadgeChoice>,
+    Option<&'a AptitudeChoice>,
+);
+type ButtonInput = ButtonComponents<'static>;
+
+// Boutons cliqués à cette image
+type PressedButtons<'w, 's> =
+    Query<'w, 's, (&'static Interaction, ButtonInput), ChangedButton>;
+
+// Entrée correspondant à un clic sur un bouton, selon l'action qu'il porte
+fn button_input((menu, game, text, badge, aptitude): ButtonComponents) -> Option<BevyInput> {
+    menu.map(|action| BevyInput::Menu(action.clone()))
+        .or_else(|| game.map(|action| BevyInput::Game(action.clone())))
+        .or_else(|| text.map(|field| BevyInput::TextField(field.clone())))
+        .or_else(|| badge.map(|badge| BevyInput::Badge(badge.0)))
+        .or_else(|| aptitude.map(|aptitude| BevyInput::Aptitude(aptitude.0)))
+}
+
+/// Enregistre les clics sur les boutons et les touches pressées, là où ils entrent dans le jeu
+pub fn record_inputs(
+    mut recording: ResMut<ReplayRecording>,
+    buttons: PressedButtons,
+    mut keyboard_events: EventReader<KeyboardInput>,
+) {
+    let Some(recorder) = recording.0.as_mut() else {
+        keyboard_events.clear();
+        return;
+    };
+
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Some(input) = button_input(button) {
+            recorder.record(&input);
+        }
+    }
+    for event in keyboard_events.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+        if let Some(key) = RecordedKey::from_key(&event.logical_key) {
+            recorder.record(&BevyInput::Key(key));
+        }
+    }
+}
+
+/// Rejoue les entrées d'une session enregistrée, au rythme où elles ont été jouées.
+///
+/// Un clic est rejoué sur le bouton qui porte la même action, dès qu'il est affiché,
+/// et une touche est renvoyée comme un événement clavier : les systèmes du jeu les traitent
+/// comme celles du joueur. Une image est laissée après chaque clic pour que le nouvel écran
+/// s'affiche. Les clics et les touches du joueur sont ignorés jusqu'à la fin du rejeu,
+/// annoncée dans le bandeau du rejeu.
+pub fn play_replay(
+    mut playback: ResMut<ReplayPlayback>,
+    mut buttons: Query<(&mut Interaction, ButtonInput), With<Button>>,
+    mut keyboard_events: ResMut<Events<KeyboardInput>>,
+    window: Query<Entity, With<PrimaryWindow>>,
+    mut banner: Query<&mut Text, With<ReplayBanner>>,
+    mut wait_for_screen: Local<bool>,
+) {
+    let Some(player) = playback.0.as_mut() else {
+        return;
+    };
+    if player.is_finished() {
+        return;
+    }
+
+    keyboard_events.clear();
+    for (mut interaction, _) in buttons.iter_mut() {
+        if *interaction == Interaction::Pressed {
+            *interaction = Interaction::Hovered;
+        }
+    }
+    if std::mem::take(&mut *wait_for_screen) {
+        return;
+    }
+
+    while let Some(input) = player.next_due() {
+        match input {
+            BevyInput::Key(key) => {
+                let Ok(window) = window.get_single() else {
+                    return;
+                };
+                keyboard_events.send(KeyboardInput {
+                    key_code: KeyCode::Unidentified(NativeKeyCode::Unidentified),
+                    logical_key: key.to_key(),
+                    state: ButtonState::Pressed,
+                    repeat: false,
+                    window,
+                });
+                player.advance();
+            }
+            _ => {
+                // Le bouton n'est pas encore affiché : on réessaie à la prochaine image
+                let Some((mut interaction, _)) = buttons
+                    .iter_mut()
+                    .find(|(_, button)| button_input(*button).as_ref() == Some(input))
+                else {
+                    return;
+                };
+                *interaction = Interaction::Pressed;
+                player.advance();
+                *wait_for_screen = true;
+                break;
+            }
+        }
+    }
+
+    if player.is_finished() {
+        for mut text in banner.iter_mut() {
+            *text = Text::new("Fin du rejeu : vous avez la main");
+        }
+    }
+}
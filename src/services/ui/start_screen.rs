@@ -1,9 +1,8 @@
 use crate::services::ui::constants::{AppState, ButtonAction, BLACK, NORMAL_BUTTON, WHITE};
 use crate::services::ui::player_slot_screen::SelectedPlayerSlot;
+use crate::services::json_loader::{JsonLoader, LoadError, SaveError};
+use crate::services::ui::game::SaveDirectory;
 use bevy::prelude::*;
-use std::fs::File;
-use std::io::Read;
-use std::path::Path;
 
 // Composant pour marquer les entites de l'ecran de demarrage
 #[derive(Component)]
@@ -37,45 +36,51 @@ impl Plugin for StartScreenPlugin {
     }
 }
 
-// Systeme pour charger le contenu de la sauvegarde
+// Systeme pour charger le resume de la sauvegarde
 pub fn load_save_content(
     mut start_text: ResMut<StartScreenText>,
     selected_slot: Res<SelectedPlayerSlot>,
+    save_dir: Res<SaveDirectory>,
 ) {
-    if let Some(slot) = selected_slot.slot {
-        // On construit le chemin du fichier de sauvegarde
-        let file_path = format!("save/player_slot_{}.json", slot + 1);
-
-        if Path::new(&file_path).exists() {
-            match File::open(&file_path) {
-                Ok(mut file) => {
-                    let mut content = String::new();
-                    if file.read_to_string(&mut content).is_ok() {
-                        // On essaye de formater en JSON pour une meilleure lisibilite
-                        match serde_json::from_str::<serde_json::Value>(&content) {
-                            Ok(json) => {
-                                start_text.content =
-                                    serde_json::to_string_pretty(&json).unwrap_or(content);
-                            }
-                            Err(_) => {
-                                // Si ce n'est pas du JSON, on affiche en brut
-                                start_text.content = content;
-                            }
-                        }
-                    } else {
-                        start_text.content = "Erreur lors de la lecture du fichier.".to_string();
-                    }
-                }
-                Err(_) => {
-                    start_text.content =
-                        "Impossible d'ouvrir le fichier de sauvegarde.".to_string();
-                }
-            }
-        } else {
-            start_text.content = "Le fichier de sauvegarde n'existe pas.".to_string();
-        }
-    } else {
+    let Some(slot) = selected_slot.slot else {
         start_text.content = "Aucun slot selectionne.".to_string();
+        return;
+    };
+
+    match JsonLoader::load_save_game(&save_dir.slot_path(slot)) {
+        Ok(save) => {
+            let player = &save.player;
+            let badges: Vec<&str> = player.badges_won.iter().map(|b| b.name.as_str()).collect();
+            start_text.content = format!(
+                "{} ({})\nNiveau {} ({} XP) - Reputation {}\nHP {}/{} - PP {}/{} - Bourse {}\nAptitudes : {}\nBadges gagnes : {}",
+                player.caracter.name,
+                player.caracter.style,
+                player.level,
+                player.xp,
+                player.reputation,
+                player.caracter.hp,
+                player.max_hp,
+                player.caracter.pp,
+                player.max_pp,
+                player.caracter.bankroll,
+                player
+                    .aptitudes
+                    .iter()
+                    .map(|a| a.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                if badges.is_empty() { "aucun".to_string() } else { badges.join(", ") },
+            );
+        }
+        Err(SaveError::Load(LoadError::NotFound { .. })) => {
+            start_text.content = format!("Le slot {} est vide.", slot + 1);
+        }
+        Err(SaveError::Migration { message, .. }) => {
+            start_text.content = format!("Sauvegarde incompatible : {}", message);
+        }
+        Err(e) => {
+            start_text.content = format!("Impossible de charger la sauvegarde : {}", e);
+        }
     }
 }
 